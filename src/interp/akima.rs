@@ -0,0 +1,150 @@
+use crate::interp::hermite::hermite_cubic;
+use crate::interp::interpolator::Interpolate1D;
+use crate::table::bisect_hunt::BisectHunt1D;
+use crate::table::search::Search;
+use ndarray::prelude::*;
+
+/// 1 Dimensional Akima interpolator
+/// Piecewise cubic Hermite with locally weighted slopes, which does not ring across steps
+pub struct Akima1D {
+    x: Array1<f64>,       // x  table
+    y: Array1<f64>,       // y  table
+    d: Array1<f64>,       // y' table
+    search: BisectHunt1D, // Search Algorithm
+}
+
+impl Akima1D {
+    /// Constructor
+    /// #  Arguments
+    /// * `x` - A monotonicly ascending or decending table of `x\[0\], ..., x\[n-1\]`
+    /// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
+    /// # Returns
+    /// * `Self` - Akima1D Instaciate
+    pub fn new(x: Array1<f64>, y: Array1<f64>) -> Self {
+        assert_eq!(
+            x.len(),
+            y.len(),
+            "Akima1D: `x` and `y` must have equal length"
+        );
+        assert!(x.len() >= 2, "Akima1D: at least 2 points are required");
+
+        let search = BisectHunt1D::new(&x, 2);
+        let d = Self::set_d(&x, &y);
+
+        Self { x, y, d, search }
+    }
+
+    /// Akima's derivative estimates at the knots
+    fn set_d(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
+        let n = x.len();
+
+        if n == 2 {
+            return Array1::from_elem(n, (y[1] - y[0]) / (x[1] - x[0]));
+        }
+
+        // Segment slopes, padded with two extrapolated slopes at each end
+        let mut m = Array1::<f64>::zeros([n + 3]);
+        for k in 0..n - 1 {
+            m[k + 2] = (y[k + 1] - y[k]) / (x[k + 1] - x[k]);
+        }
+        m[1] = 2.0 * m[2] - m[3];
+        m[0] = 2.0 * m[1] - m[2];
+        m[n + 1] = 2.0 * m[n] - m[n - 1];
+        m[n + 2] = 2.0 * m[n + 1] - m[n];
+
+        // Weight each side by how much the slopes change on the other side
+        Array1::from_shape_fn(n, |i| {
+            let w1 = (m[i + 3] - m[i + 2]).abs();
+            let w2 = (m[i + 1] - m[i]).abs();
+
+            if w1 + w2 == 0.0 {
+                0.5 * (m[i + 1] + m[i + 2])
+            } else {
+                (w1 * m[i + 1] + w2 * m[i + 2]) / (w1 + w2)
+            }
+        })
+    }
+}
+
+impl Interpolate1D for Akima1D {
+    type Dtype = f64;
+    type Data = Array1<Self::Dtype>;
+    type Index = usize;
+
+    /// Interpolation
+    /// #  Arguments
+    /// * `x` - The x value for which `f(x)` is being approximated
+    /// # Returns
+    /// * `y : f64` - An approximation of `f(x)`
+    fn interpolate(&mut self, x: Self::Dtype) -> Self::Dtype {
+        // Get indecies
+        let i = self.search.locate(&self.x, x);
+
+        // Evaluate
+        hermite_cubic(
+            x,
+            self.x[i],
+            self.x[i + 1],
+            self.y[i],
+            self.y[i + 1],
+            self.d[i],
+            self.d[i + 1],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interp::spline::{BoundaryCondition, Spline1D};
+    use crate::test_utils::knots;
+
+    // Rise then fall between plateaus. Each level spans two intervals, which is what lets
+    // Akima's weights flatten the slopes at the steps
+    fn plateaus() -> (Array1<f64>, Array1<f64>) {
+        (
+            arr1(&[0.0, 0.5, 1.5, 2.0, 3.0, 3.4, 4.5, 5.0, 6.0]),
+            arr1(&[1.0, 1.0, 1.0, 3.0, 3.0, 3.0, 0.0, 0.0, 0.0]),
+        )
+    }
+
+    // Largest excursion of `f` outside the range of the knot values on any interval
+    fn overshoot(x: &Array1<f64>, y: &Array1<f64>, mut f: impl FnMut(f64) -> f64) -> f64 {
+        (0..x.len() - 1).fold(0., |acc: f64, i| {
+            let (lo, hi) = (y[i].min(y[i + 1]), y[i].max(y[i + 1]));
+            Array1::linspace(x[i], x[i + 1], 101)
+                .iter()
+                .fold(acc, |acc, &x_q| {
+                    let y_q = f(x_q);
+                    acc.max(lo - y_q).max(y_q - hi)
+                })
+        })
+    }
+
+    #[test]
+    fn does_not_ring_across_steps() {
+        let (x, y) = plateaus();
+        let mut akima = Akima1D::new(x.clone(), y.clone());
+        assert!(overshoot(&x, &y, |x_q| akima.interpolate(x_q)) < 1e-12);
+
+        // Whereas a spline through the same data does
+        let mut spline = Spline1D::new(
+            x.clone(),
+            y.clone(),
+            BoundaryCondition::Natural,
+            BoundaryCondition::Natural,
+        );
+        assert!(overshoot(&x, &y, |x_q| spline.interpolate(x_q)) > 0.1);
+    }
+
+    #[test]
+    fn reproduces_linear_data() {
+        let x = knots();
+        let y = x.mapv(|x| 1.5 - 2.0 * x);
+        let mut akima = Akima1D::new(x, y);
+
+        for &x_q in Array1::linspace(-1., 2., 61).iter() {
+            assert!((akima.interpolate(x_q) - (1.5 - 2.0 * x_q)).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::interp::interpolator::Interpolate1D;
+use ndarray::prelude::*;
+use std::fmt;
+
+/// Error returned when the order of approximation is too large for the table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidOrder {
+    pub d: usize, // Requested order
+    pub n: usize, // Table length
+}
+
+impl fmt::Display for InvalidOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "order of approximation {} must be lesser than the table length {}",
+            self.d, self.n
+        )
+    }
+}
+
+impl std::error::Error for InvalidOrder {}
+
+/// 1 Dimensional barycentric rational interpolator
+/// Based on Floater & Hormann's weights. Has no poles on the real axis
+pub struct BaryRat1D {
+    x: Array1<f64>, // x table
+    y: Array1<f64>, // y table
+    w: Array1<f64>, // Barycentric weights
+    d: usize,       // Order of the blended polynomials
+}
+
+impl BaryRat1D {
+    /// Constructor
+    /// #  Arguments
+    /// * `x` - A monotonicly ascending or decending table of `x\[0\], ..., x\[n-1\]`
+    /// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
+    /// * `d` - Order of approximation. Must be lesser than the length of `x`. 3 is a good default
+    /// # Returns
+    /// * `Result<Self, InvalidOrder>` - BaryRat1D Instaciate, or `Err` if `d` is too large
+    pub fn new(x: Array1<f64>, y: Array1<f64>, d: usize) -> Result<Self, InvalidOrder> {
+        let n = x.len();
+
+        assert_eq!(y.len(), n, "BaryRat1D: `x` and `y` must have equal length");
+
+        if d >= n {
+            return Err(InvalidOrder { d, n });
+        }
+
+        let mut w = Array1::<f64>::zeros([n]);
+
+        for k in 0..n {
+            // Every degree-d polynomial through x[i], ..., x[i + d] that contains x[k]
+            let imin = k.saturating_sub(d);
+            let imax = if k >= n - d { n - d - 1 } else { k };
+
+            let mut temp = if imin & 1 == 1 { -1.0 } else { 1.0 };
+            let mut sum = 0.0;
+
+            for i in imin..=imax {
+                let jmax = (i + d).min(n - 1);
+                let term = (i..=jmax)
+                    .filter(|&j| j != k)
+                    .fold(1.0, |acc, j| acc * (x[k] - x[j]));
+                sum += temp / term;
+                temp = -temp;
+            }
+
+            w[k] = sum;
+        }
+
+        Ok(Self { x, y, w, d })
+    }
+
+    /// Order of the blended polynomials
+    pub fn order(&self) -> usize {
+        self.d
+    }
+}
+
+impl Interpolate1D for BaryRat1D {
+    type Dtype = f64;
+    type Data = Array1<Self::Dtype>;
+    type Index = usize;
+
+    /// Interpolation
+    /// #  Arguments
+    /// * `x` - The x value for which `f(x)` is being approximated
+    /// # Returns
+    /// * `y : f64` - An approximation of `f(x)`
+    fn interpolate(&mut self, x: Self::Dtype) -> Self::Dtype {
+        let mut num = 0.0;
+        let mut den = 0.0;
+
+        for ((&x_i, &y_i), &w_i) in self.x.iter().zip(self.y.iter()).zip(self.w.iter()) {
+            let h = x - x_i;
+
+            // Exact hit on a node
+            if h == 0.0 {
+                return y_i;
+            }
+
+            let temp = w_i / h;
+            num += temp * y_i;
+            den += temp;
+        }
+
+        num / den
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::knots;
+
+    #[test]
+    fn hits_nodes_exactly() {
+        let x = knots();
+        let y = x.mapv(|x| (3.0 * x).sin());
+        let mut bary = BaryRat1D::new(x.clone(), y.clone(), 3).unwrap();
+
+        for (&x_k, &y_k) in x.iter().zip(y.iter()) {
+            assert_eq!(bary.interpolate(x_k), y_k);
+        }
+    }
+
+    #[test]
+    fn reproduces_polynomials_up_to_order() {
+        let x = knots();
+
+        for d in 0..4 {
+            let p = |x: f64| (0..=d).fold(0.0, |acc, k| acc * x + (k as f64 - 1.5));
+            let mut bary = BaryRat1D::new(x.clone(), x.mapv(p), d).unwrap();
+            assert_eq!(bary.order(), d);
+
+            for &x_q in Array1::linspace(-1., 2., 61).iter() {
+                assert!((bary.interpolate(x_q) - p(x_q)).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn converges_on_runge_function_with_even_spacing() {
+        // Where a global polynomial through the same points diverges
+        let f = |x: f64| 1.0 / (1.0 + 25.0 * x * x);
+        let x = Array1::linspace(-1., 1., 101);
+        let mut bary = BaryRat1D::new(x.clone(), x.mapv(f), 3).unwrap();
+
+        for &x_q in Array1::linspace(-1., 1., 1001).iter() {
+            assert!((bary.interpolate(x_q) - f(x_q)).abs() < 1e-7);
+        }
+    }
+
+    #[test]
+    fn order_must_fit_the_table() {
+        let x = knots();
+        let y = x.mapv(|x| x * x);
+
+        assert_eq!(
+            BaryRat1D::new(x, y, 8).err(),
+            Some(InvalidOrder { d: 8, n: 8 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must have equal length")]
+    fn short_y_is_rejected() {
+        let x = arr1(&[0.0, 1.0, 2.0, 3.0]);
+        let y = arr1(&[0.0, 1.0]);
+        let _ = BaryRat1D::new(x, y, 1);
+    }
+}
@@ -0,0 +1,287 @@
+use crate::interp::interpolator::InterpolateND;
+use crate::table::bisect_hunt::BisectHunt1D;
+use crate::table::search::Search;
+use ndarray::prelude::*;
+use ndarray::Zip;
+
+/// 2 Dimensional bicubic interpolator on a rectangular grid
+/// Matches `f`, `df/dx1`, `df/dx2` and `d2f/dx1dx2` at the grid points, giving a C1 surface
+pub struct Bicubic2D {
+    x1: Array1<f64>,       // x1 table
+    x2: Array1<f64>,       // x2 table
+    y: Array2<f64>,        // y table
+    y1: Array2<f64>,       // df/dx1 table
+    y2: Array2<f64>,       // df/dx2 table
+    y12: Array2<f64>,      // d2f/dx1dx2 table
+    search1: BisectHunt1D, // Search Algorithm x1
+    search2: BisectHunt1D, // Search Algorithm x2
+}
+
+impl Bicubic2D {
+    /// Constructor
+    /// #  Arguments
+    /// * `x1` - A monotonicly ascending or decending table of `x1\[0\], ..., x1\[m-1\]`
+    /// * `x2` - A monotonicly ascending or decending table of `x2\[0\], ..., x2\[n-1\]`
+    /// * `y` - An `m x n` table of `f(x1\[i\], x2\[j\])`
+    /// * `dy` - Optional `m x n` tables `\[df/dx1, df/dx2, d2f/dx1dx2\]`. Estimated by finite differences if `None`
+    /// # Returns
+    /// * `Self` - Bicubic2D Instaciate
+    pub fn new(
+        x1: Array1<f64>,
+        x2: Array1<f64>,
+        y: Array2<f64>,
+        dy: Option<[Array2<f64>; 3]>,
+    ) -> Self {
+        assert!(
+            x1.len() >= 2 && x2.len() >= 2,
+            "Bicubic2D: at least 2 points are required along each axis"
+        );
+        assert_eq!(
+            y.dim(),
+            (x1.len(), x2.len()),
+            "Bicubic2D: `y` must have shape (x1.len(), x2.len())"
+        );
+
+        let [y1, y2, y12] = match dy {
+            Some(dy) => {
+                assert!(
+                    dy.iter().all(|d| d.dim() == y.dim()),
+                    "Bicubic2D: derivative tables must have the same shape as `y`"
+                );
+                dy
+            }
+            None => {
+                let y1 = differentiate(&x1, &y, Axis(0));
+                let y2 = differentiate(&x2, &y, Axis(1));
+                let y12 = differentiate(&x1, &y2, Axis(0));
+                [y1, y2, y12]
+            }
+        };
+
+        // Instaciate search algorithms
+        let search1 = BisectHunt1D::new(&x1, 2);
+        let search2 = BisectHunt1D::new(&x2, 2);
+        Self {
+            x1,
+            x2,
+            y,
+            y1,
+            y2,
+            y12,
+            search1,
+            search2,
+        }
+    }
+
+    /// Gradient of the interpolating surface
+    /// #  Arguments
+    /// * `x` - The point `\[x1, x2\]` at which the gradient is being approximated
+    /// # Returns
+    /// * `\[df/dx1, df/dx2\]` - An approximation of the gradient of `f`
+    pub fn gradient(&mut self, x: [f64; 2]) -> [f64; 2] {
+        let [x1, x2] = x;
+
+        // Get indecies
+        let i = self.search1.locate(&self.x1, x1);
+        let j = self.search2.locate(&self.x2, x2);
+
+        // Evaluate
+        let (b1, db1) = basis(x1, self.x1[i], self.x1[i + 1]);
+        let (b2, db2) = basis(x2, self.x2[j], self.x2[j + 1]);
+        [self.combine(&db1, &b2, i, j), self.combine(&b1, &db2, i, j)]
+    }
+
+    /// Sums the tensor product of the basis functions against the corner data of cell `i, j`
+    fn combine(&self, b1: &[f64; 4], b2: &[f64; 4], i: usize, j: usize) -> f64 {
+        let mut y = 0.0;
+        for a in 0..2 {
+            for b in 0..2 {
+                let k = [i + a, j + b];
+                y += b1[a] * b2[b] * self.y[k]
+                    + b1[2 + a] * b2[b] * self.y1[k]
+                    + b1[a] * b2[2 + b] * self.y2[k]
+                    + b1[2 + a] * b2[2 + b] * self.y12[k];
+            }
+        }
+        y
+    }
+}
+
+/// Cubic Hermite basis on `x0, x1`, and its derivative
+/// # Returns
+/// * `(b, db)` - Weights of `y(x0), y(x1), y'(x0), y'(x1)` and their derivatives with respect to `x`
+fn basis(x: f64, x0: f64, x1: f64) -> ([f64; 4], [f64; 4]) {
+    let h = x1 - x0;
+    let t = (x - x0) / h;
+
+    let b = [
+        (1.0 + 2.0 * t) * (1.0 - t) * (1.0 - t),
+        t * t * (3.0 - 2.0 * t),
+        h * t * (1.0 - t) * (1.0 - t),
+        h * t * t * (t - 1.0),
+    ];
+    let db = [
+        6.0 * t * (t - 1.0) / h,
+        6.0 * t * (1.0 - t) / h,
+        (1.0 - t) * (1.0 - 3.0 * t),
+        t * (3.0 * t - 2.0),
+    ];
+
+    (b, db)
+}
+
+/// Second order finite difference derivative of `y` along `axis`
+fn differentiate(x: &Array1<f64>, y: &Array2<f64>, axis: Axis) -> Array2<f64> {
+    let n = x.len();
+    let h = Array1::from_shape_fn(n - 1, |k| x[k + 1] - x[k]);
+
+    let mut dy = Array2::<f64>::zeros(y.raw_dim());
+
+    Zip::from(dy.lanes_mut(axis))
+        .and(y.lanes(axis))
+        .for_each(|mut d, y| {
+            let delta = Array1::from_shape_fn(n - 1, |k| (y[k + 1] - y[k]) / h[k]);
+
+            if n == 2 {
+                d.fill(delta[0]);
+                return;
+            }
+
+            // Derivative of the parabola through three neighbouring points
+            for k in 1..n - 1 {
+                d[k] = (h[k] * delta[k - 1] + h[k - 1] * delta[k]) / (h[k - 1] + h[k]);
+            }
+            d[0] = ((2.0 * h[0] + h[1]) * delta[0] - h[0] * delta[1]) / (h[0] + h[1]);
+            d[n - 1] = ((2.0 * h[n - 2] + h[n - 3]) * delta[n - 2] - h[n - 2] * delta[n - 3])
+                / (h[n - 2] + h[n - 3]);
+        });
+
+    dy
+}
+
+impl InterpolateND for Bicubic2D {
+    type YDim = Ix2;
+
+    type Dtype = f64;
+
+    type X<'a> = [f64; 2];
+    type IndX = [usize; 2];
+
+    type Y = f64;
+    type IndY = [usize; 2];
+
+    /// Interpolation
+    /// #  Arguments
+    /// * `x` - The point `\[x1, x2\]` for which `f(x1, x2)` is being approximated
+    /// # Returns
+    /// * `y : f64` - An approximation of `f(x1, x2)`
+    fn interpolate(&mut self, x: Self::X<'_>) -> Self::Y {
+        let [x1, x2] = x;
+
+        // Get indecies
+        let i = self.search1.locate(&self.x1, x1);
+        let j = self.search2.locate(&self.x2, x2);
+
+        // Evaluate
+        let (b1, _) = basis(x1, self.x1[i], self.x1[i + 1]);
+        let (b2, _) = basis(x2, self.x2[j], self.x2[j + 1]);
+        self.combine(&b1, &b2, i, j)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::grid;
+
+    // Bicubic f with its partial derivatives
+    fn f(x1: f64, x2: f64) -> f64 {
+        (x1 * x1 * x1 - x1) * (2.0 * x2 * x2 * x2 + x2 * x2 - 1.0) + x1 * x2
+    }
+
+    fn f1(x1: f64, x2: f64) -> f64 {
+        (3.0 * x1 * x1 - 1.0) * (2.0 * x2 * x2 * x2 + x2 * x2 - 1.0) + x2
+    }
+
+    fn f2(x1: f64, x2: f64) -> f64 {
+        (x1 * x1 * x1 - x1) * (6.0 * x2 * x2 + 2.0 * x2) + x1
+    }
+
+    fn f12(x1: f64, x2: f64) -> f64 {
+        (3.0 * x1 * x1 - 1.0) * (6.0 * x2 * x2 + 2.0 * x2) + 1.0
+    }
+
+    fn bicubic() -> Bicubic2D {
+        let (x1, x2) = grid();
+        let table = |g: fn(f64, f64) -> f64| {
+            Array2::from_shape_fn([x1.len(), x2.len()], |(i, j)| g(x1[i], x2[j]))
+        };
+        let y = table(f);
+        let dy = [table(f1), table(f2), table(f12)];
+        Bicubic2D::new(x1, x2, y, Some(dy))
+    }
+
+    // Quadratic along each axis, so the three point differences are exact
+    fn biquadratic(x1: f64, x2: f64) -> f64 {
+        (x1 * x1 - 2.0 * x1 + 0.5) * (1.0 + x2 - 3.0 * x2 * x2) + x1 * x2
+    }
+
+    fn estimated(x1: Array1<f64>, x2: Array1<f64>) -> Bicubic2D {
+        let y = Array2::from_shape_fn([x1.len(), x2.len()], |(i, j)| biquadratic(x1[i], x2[j]));
+        Bicubic2D::new(x1, x2, y, None)
+    }
+
+    #[test]
+    fn reproduces_bicubic_data() {
+        let mut interp = bicubic();
+
+        for &x1 in Array1::linspace(-1., 2., 31).iter() {
+            for &x2 in Array1::linspace(0., 1.5, 16).iter() {
+                assert!((interp.interpolate([x1, x2]) - f(x1, x2)).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn gradient_matches_analytic_gradient() {
+        let mut interp = bicubic();
+
+        for &x1 in Array1::linspace(-1., 2., 31).iter() {
+            for &x2 in Array1::linspace(0., 1.5, 16).iter() {
+                let [g1, g2] = interp.gradient([x1, x2]);
+                assert!((g1 - f1(x1, x2)).abs() < 1e-11);
+                assert!((g2 - f2(x1, x2)).abs() < 1e-11);
+            }
+        }
+    }
+
+    #[test]
+    fn finite_differences_reproduce_biquadratic_data() {
+        let (x1, x2) = grid();
+        let mut interp = estimated(x1, x2);
+
+        for &x1 in Array1::linspace(-1., 2., 31).iter() {
+            for &x2 in Array1::linspace(0., 1.5, 16).iter() {
+                assert!((interp.interpolate([x1, x2]) - biquadratic(x1, x2)).abs() < 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn descending_axes_match_ascending() {
+        let (x1, x2) = grid();
+        let mut ascending = estimated(x1.clone(), x2.clone());
+        let mut descending = estimated(
+            x1.slice(s![..;-1]).to_owned(),
+            x2.slice(s![..;-1]).to_owned(),
+        );
+
+        for &x1 in Array1::linspace(-1., 2., 31).iter() {
+            for &x2 in Array1::linspace(0., 1.5, 16).iter() {
+                let diff = ascending.interpolate([x1, x2]) - descending.interpolate([x1, x2]);
+                assert!(diff.abs() < 1e-14);
+                assert!((descending.interpolate([x1, x2]) - biquadratic(x1, x2)).abs() < 1e-14);
+            }
+        }
+    }
+}
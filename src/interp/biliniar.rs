@@ -1,78 +1,178 @@
-// use crate::interp::interpolator::Interpolate;
-// use crate::table::bisect_hunt::BisectHunt1D;
-// use crate::table::search::Search;
-// use ndarray::{prelude::*, stack, Axis};
-// use plotters::prelude::*;
-
-// /// 1 Dimensional linear Instaciatenterpolator
-// pub struct Linear2D {
-//     x1: Array1<f64>,       // x1 table
-//     x2: Array1<f64>,       // x2 table
-//     y: Array2<f64>,        // y table
-//     search1: BisectHunt1D, // Search Algorithm x1
-//     search2: BisectHunt1D, // Search Algorithm x2
-// }
-
-// impl Linear2D {
-//     /// Constructor
-//     /// #  Arguments
-//     /// * `x1` - A monotonicly ascending or decending table of `x\[0\], ..., x\[n-1\]`
-//     /// * `x2` - A monotonicly ascending or decending table of `x\[0\], ..., x\[n-1\]`
-//     /// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
-//     /// # Returns
-//     /// * `Self` - Linear2D Instance
-//     pub fn new(x1: Array1<f64>, x2: Array1<f64>, y: Array2<f64>) -> Self {
-//         // Instaciate search algorithm
-//         let search1 = BisectHunt1D::new(&x1, 2);
-//         let search2 = BisectHunt1D::new(&x2, 2);
-//         Self {
-//             x1: x1,
-//             x2: x2,
-//             y: y,
-//             search1: search1,
-//             search2: search2,
-//         }
-//     }
-
-//     fn interpolate(&mut self, x: [f64; 2]) -> f64 {
-//         let [x1, x2] = x;
-
-//         // Get indicies
-//         let i = self.search1.locate(&self.x1, x1);
-//         let j = self.search2.locate(&self.x2, x2);
-
-//         // Evaluate
-//         let t = (x1 - self.x1[i]) / (self.x1[i + 1] - self.x1[i]);
-//         let u = (x2 - self.x2[j]) / (self.x2[j + 1] - self.x2[j]);
-//         let y = (1. - t) * (1. - u) * self.y[[i, j]]
-//             + t * (1. - u) * self.y[[i + 1, j]]
-//             + (1. - t) * u * self.y[[i, j + 1]]
-//             + t * u * self.y[[i + 1, j + 1]];
-
-//         return y;
-//     }
-// }
-
-// pub fn proof() {
-//     // Revisit this
-//     println!("Starting proof...");
-
-//     fn f(x1: &f64, x2: &f64) -> f64 {
-//         x1 - x2
-//     }
-
-//     let x1 = Array1::linspace(0., 1., 5);
-//     let x2 = Array1::linspace(0., 2., 5);
-
-//     let mut y = Array2::<f64>::zeros([x1.len(), x2.len()]);
-
-//     for i in 0..x1.len() {
-//         for j in 0..x2.len() {
-//             y[[i, j]] = f(&x1[i], &x2[j]);
-//         }
-//     }
-
-//     for row in y.iter() {
-//         println!("{}", row)
-//     }
-// }
+use crate::interp::interpolator::InterpolateND;
+use crate::table::bisect_hunt::BisectHunt1D;
+use crate::table::search::Search;
+use ndarray::prelude::*;
+use plotters::prelude::*;
+
+/// 2 Dimensional bilinear interpolator on a rectangular grid
+pub struct Bilinear2D {
+    x1: Array1<f64>,       // x1 table
+    x2: Array1<f64>,       // x2 table
+    y: Array2<f64>,        // y table
+    search1: BisectHunt1D, // Search Algorithm x1
+    search2: BisectHunt1D, // Search Algorithm x2
+}
+
+impl Bilinear2D {
+    /// Constructor
+    /// #  Arguments
+    /// * `x1` - A monotonicly ascending or decending table of `x1\[0\], ..., x1\[m-1\]`
+    /// * `x2` - A monotonicly ascending or decending table of `x2\[0\], ..., x2\[n-1\]`
+    /// * `y` - An `m x n` table of `f(x1\[i\], x2\[j\])`
+    /// # Returns
+    /// * `Self` - Bilinear2D Instaciate
+    pub fn new(x1: Array1<f64>, x2: Array1<f64>, y: Array2<f64>) -> Self {
+        assert!(
+            x1.len() >= 2 && x2.len() >= 2,
+            "Bilinear2D: at least 2 points are required along each axis"
+        );
+        assert_eq!(
+            y.dim(),
+            (x1.len(), x2.len()),
+            "Bilinear2D: `y` must have shape (x1.len(), x2.len())"
+        );
+
+        // Instaciate search algorithms
+        let search1 = BisectHunt1D::new(&x1, 2);
+        let search2 = BisectHunt1D::new(&x2, 2);
+        Self {
+            x1,
+            x2,
+            y,
+            search1,
+            search2,
+        }
+    }
+}
+
+impl InterpolateND for Bilinear2D {
+    type YDim = Ix2;
+
+    type Dtype = f64;
+
+    type X<'a> = [f64; 2];
+    type IndX = [usize; 2];
+
+    type Y = f64;
+    type IndY = [usize; 2];
+
+    /// Interpolation
+    /// #  Arguments
+    /// * `x` - The point `\[x1, x2\]` for which `f(x1, x2)` is being approximated
+    /// # Returns
+    /// * `y : f64` - An approximation of `f(x1, x2)`
+    fn interpolate(&mut self, x: Self::X<'_>) -> Self::Y {
+        let [x1, x2] = x;
+
+        // Get indecies
+        let i = self.search1.locate(&self.x1, x1);
+        let j = self.search2.locate(&self.x2, x2);
+
+        // Evaluate
+        let t = (x1 - self.x1[i]) / (self.x1[i + 1] - self.x1[i]);
+        let u = (x2 - self.x2[j]) / (self.x2[j + 1] - self.x2[j]);
+
+        (1. - t) * (1. - u) * self.y[[i, j]]
+            + t * (1. - u) * self.y[[i + 1, j]]
+            + (1. - t) * u * self.y[[i, j + 1]]
+            + t * u * self.y[[i + 1, j + 1]]
+    }
+}
+
+pub fn proof() {
+    // Revisit this
+    println!("Starting proof...");
+
+    fn f(x1: f64, x2: f64) -> f64 {
+        f64::sin(x1) * f64::cos(x2)
+    }
+
+    let x1 = Array1::linspace(0., std::f64::consts::PI * 2., 9);
+    let x2 = Array1::linspace(0., std::f64::consts::PI, 5);
+    let y = Array2::from_shape_fn([x1.len(), x2.len()], |(i, j)| f(x1[i], x2[j]));
+
+    let mut my_interp = Bilinear2D::new(x1, x2, y);
+
+    let x_gt = Array1::linspace(0., std::f64::consts::PI * 2., 1000);
+
+    let root_area =
+        BitMapBackend::new("images/Bilinear2D_proof.png", (1920, 1040)).into_drawing_area();
+    root_area.fill(&WHITE).unwrap();
+
+    let mut ctx = ChartBuilder::on(&root_area)
+        .set_label_area_size(LabelAreaPosition::Left, 40)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .caption("Bilinear 2D Interpolation Proof", ("sans-serif", 40))
+        .build_cartesian_2d(0f64..(std::f64::consts::PI * 2.), -1.0f64..1.0f64)
+        .unwrap();
+
+    ctx.configure_mesh().draw().unwrap();
+
+    // Slices at fixed x2, between and on the grid lines
+    for &x2 in [0.3, std::f64::consts::PI / 4., 2.0].iter() {
+        ctx.draw_series(LineSeries::new(
+            x_gt.iter().map(|&x1| (x1, f(x1, x2))),
+            &RED,
+        ))
+        .unwrap();
+
+        ctx.draw_series(LineSeries::new(
+            x_gt.iter().map(|&x1| (x1, my_interp.interpolate([x1, x2]))),
+            &GREEN,
+        ))
+        .unwrap();
+    }
+
+    println!("Proof complete.");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::grid;
+
+    fn bilinear(x1: f64, x2: f64) -> f64 {
+        1.0 + 2.0 * x1 - 0.5 * x2 + 3.0 * x1 * x2
+    }
+
+    #[test]
+    fn reproduces_bilinear_data() {
+        let (x1, x2) = grid();
+        let y = Array2::from_shape_fn([x1.len(), x2.len()], |(i, j)| bilinear(x1[i], x2[j]));
+        let mut interp = Bilinear2D::new(x1, x2, y);
+
+        for &x1 in Array1::linspace(-1., 2., 31).iter() {
+            for &x2 in Array1::linspace(0., 1.5, 16).iter() {
+                assert!((interp.interpolate([x1, x2]) - bilinear(x1, x2)).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn descending_axis_matches_ascending() {
+        let (x1, x2) = grid();
+        let y = Array2::from_shape_fn([x1.len(), x2.len()], |(i, j)| {
+            f64::sin(x1[i]) * f64::cos(x2[j])
+        });
+        let mut ascending = Bilinear2D::new(x1.clone(), x2.clone(), y.clone());
+        let mut descending = Bilinear2D::new(
+            x1,
+            x2.slice(s![..;-1]).to_owned(),
+            y.slice(s![.., ..;-1]).to_owned(),
+        );
+
+        for &x1 in Array1::linspace(-1., 2., 31).iter() {
+            for &x2 in Array1::linspace(0., 1.5, 16).iter() {
+                let diff = ascending.interpolate([x1, x2]) - descending.interpolate([x1, x2]);
+                assert!(diff.abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "`y` must have shape")]
+    fn wrong_shape_is_rejected() {
+        let (x1, x2) = grid();
+        Bilinear2D::new(x1, x2, Array2::zeros([4, 5]));
+    }
+}
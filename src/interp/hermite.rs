@@ -0,0 +1,178 @@
+use crate::interp::interpolator::Interpolate1D;
+use crate::table::bisect_hunt::BisectHunt1D;
+use crate::table::search::Search;
+use ndarray::prelude::*;
+
+/// 1 Dimensional piecewise Hermite interpolator from supplied derivatives
+/// Cubic segments from `y` and `y'`, quintic segments when `y''` is also given
+pub struct Hermite1D {
+    x: Array1<f64>,           // x   table
+    y: Array1<f64>,           // y   table
+    dy: Array1<f64>,          // y'  table
+    d2y: Option<Array1<f64>>, // y'' table
+    search: BisectHunt1D,     // Search Algorithm
+}
+
+impl Hermite1D {
+    /// Constructor
+    /// #  Arguments
+    /// * `x` - A monotonicly ascending or decending table of `x\[0\], ..., x\[n-1\]`
+    /// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
+    /// * `dy` - A table of `f'(x\[0\]), ..., f'(x\[n-1\])`
+    /// * `d2y` - An optional table of `f''(x\[0\]), ..., f''(x\[n-1\])`. Gives quintic segments if supplied
+    /// # Returns
+    /// * `Self` - Hermite1D Instaciate
+    pub fn new(x: Array1<f64>, y: Array1<f64>, dy: Array1<f64>, d2y: Option<Array1<f64>>) -> Self {
+        let n = x.len();
+
+        assert!(n >= 2, "Hermite1D: at least 2 points are required");
+        assert!(
+            y.len() == n && dy.len() == n,
+            "Hermite1D: `x`, `y` and `dy` must have equal length"
+        );
+        if let Some(d2y) = &d2y {
+            assert_eq!(
+                d2y.len(),
+                n,
+                "Hermite1D: `x` and `d2y` must have equal length"
+            );
+        }
+
+        let search = BisectHunt1D::new(&x, 2);
+
+        Self {
+            x,
+            y,
+            dy,
+            d2y,
+            search,
+        }
+    }
+
+    /// Degree of the polynomial segments : 3 or 5
+    pub fn degree(&self) -> usize {
+        if self.d2y.is_some() {
+            5
+        } else {
+            3
+        }
+    }
+
+    /// Raw Interpolator
+    /// #  Arguments
+    /// * `x` - The x value for which `f(x)` is being approximated
+    /// * `i` - The first index of the segment `x\[i\], x\[i + 1\]`
+    /// # Returns
+    /// * `y : f64` - An approximation of `f(x)`
+    pub fn raw_interpolate(&self, x: f64, i: usize) -> f64 {
+        let d2y = match &self.d2y {
+            Some(d2y) => d2y,
+            None => {
+                return hermite_cubic(
+                    x,
+                    self.x[i],
+                    self.x[i + 1],
+                    self.y[i],
+                    self.y[i + 1],
+                    self.dy[i],
+                    self.dy[i + 1],
+                )
+            }
+        };
+
+        let h = self.x[i + 1] - self.x[i];
+        let t = (x - self.x[i]) / h;
+        let (t2, t3) = (t * t, t * t * t);
+        let (t4, t5) = (t3 * t, t3 * t2);
+
+        // Quintic Hermite basis functions
+        let h0 = 1.0 - 10.0 * t3 + 15.0 * t4 - 6.0 * t5;
+        let h1 = t - 6.0 * t3 + 8.0 * t4 - 3.0 * t5;
+        let h2 = 0.5 * (t2 - 3.0 * t3 + 3.0 * t4 - t5);
+        let h3 = 0.5 * (t3 - 2.0 * t4 + t5);
+        let h4 = -4.0 * t3 + 7.0 * t4 - 3.0 * t5;
+        let h5 = 10.0 * t3 - 15.0 * t4 + 6.0 * t5;
+
+        h0 * self.y[i]
+            + h1 * h * self.dy[i]
+            + h2 * h * h * d2y[i]
+            + h3 * h * h * d2y[i + 1]
+            + h4 * h * self.dy[i + 1]
+            + h5 * self.y[i + 1]
+    }
+}
+
+/// Evaluates the cubic Hermite segment matching values and slopes at both ends
+/// #  Arguments
+/// * `x` - The x value for which `f(x)` is being approximated
+/// * `x0`, `x1` - Ends of the segment
+/// * `y0`, `y1` - Values at the ends
+/// * `d0`, `d1` - Slopes at the ends
+/// # Returns
+/// * `y : f64` - An approximation of `f(x)`
+pub(crate) fn hermite_cubic(x: f64, x0: f64, x1: f64, y0: f64, y1: f64, d0: f64, d1: f64) -> f64 {
+    let h = x1 - x0;
+    let t = (x - x0) / h;
+
+    // Hermite basis functions
+    let h00 = (1.0 + 2.0 * t) * (1.0 - t) * (1.0 - t);
+    let h10 = t * (1.0 - t) * (1.0 - t);
+    let h01 = t * t * (3.0 - 2.0 * t);
+    let h11 = t * t * (t - 1.0);
+
+    h00 * y0 + h10 * h * d0 + h01 * y1 + h11 * h * d1
+}
+
+impl Interpolate1D for Hermite1D {
+    type Dtype = f64;
+    type Data = Array1<Self::Dtype>;
+    type Index = usize;
+
+    /// Interpolation
+    /// #  Arguments
+    /// * `x` - The x value for which `f(x)` is being approximated
+    /// # Returns
+    /// * `y : f64` - An approximation of `f(x)`
+    fn interpolate(&mut self, x: Self::Dtype) -> Self::Dtype {
+        // Get indecies
+        let i = self.search.locate(&self.x, x);
+
+        // Evaluate
+        self.raw_interpolate(x, i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::knots;
+
+    #[test]
+    fn quintic_segments_reproduce_quintic_data() {
+        let f = |x: f64| x.powi(5) - 2.0 * x.powi(3) + 0.5 * x - 1.0;
+        let df = |x: f64| 5.0 * x.powi(4) - 6.0 * x * x + 0.5;
+        let d2f = |x: f64| 20.0 * x.powi(3) - 12.0 * x;
+
+        let x = knots();
+        let mut hermite = Hermite1D::new(x.clone(), x.mapv(f), x.mapv(df), Some(x.mapv(d2f)));
+        assert_eq!(hermite.degree(), 5);
+
+        for &x_q in Array1::linspace(-1., 2., 61).iter() {
+            assert!((hermite.interpolate(x_q) - f(x_q)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn cubic_segments_reproduce_cubic_data() {
+        let f = |x: f64| 2.0 * x * x * x - x * x + 0.5 * x - 3.0;
+        let df = |x: f64| 6.0 * x * x - 2.0 * x + 0.5;
+
+        let x = knots();
+        let mut hermite = Hermite1D::new(x.clone(), x.mapv(f), x.mapv(df), None);
+        assert_eq!(hermite.degree(), 3);
+
+        for &x_q in Array1::linspace(-1., 2., 61).iter() {
+            assert!((hermite.interpolate(x_q) - f(x_q)).abs() < 1e-12);
+        }
+    }
+}
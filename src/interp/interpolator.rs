@@ -1,3 +1,5 @@
+use num_traits::Float;
+
 /// Interpolator Trait
 pub trait Interpolate1D {
     type Dtype; // Base datatype
@@ -6,6 +8,15 @@ pub trait Interpolate1D {
                 // Aproximates f(x) from x given a monotonicly increasing or decending
                 // tables x[0], ..., x[n-1], and y[0],  ..., y[n-1]
     fn interpolate(&mut self, x: Self::Dtype) -> Self::Dtype;
+
+    /// Aproximates f(x) as above, along with an estimate of the error in the approximation
+    /// Interpolators with no natural error estimate report NaN
+    fn interpolate_with_error(&mut self, x: Self::Dtype) -> (Self::Dtype, Self::Dtype)
+    where
+        Self::Dtype: Float,
+    {
+        (self.interpolate(x), Self::Dtype::nan())
+    }
 }
 
 pub trait InterpolateND {
@@ -13,11 +24,11 @@ pub trait InterpolateND {
 
     type Dtype;
 
-    type X;
+    type X<'a>; // Query point. May borrow, e.g. a slice with one coordinate per axis
     type IndX;
 
     type Y;
     type IndY;
 
-    fn interpolate(&mut self, x: Self::X) -> Self::Y;
+    fn interpolate(&mut self, x: Self::X<'_>) -> Self::Y;
 }
@@ -21,11 +21,7 @@ impl Linear1D {
     pub fn new(x: Array1<f64>, y: Array1<f64>) -> Self {
         // Instaciate search algorithm
         let search = BisectHunt1D::new(&x, 2);
-        Self {
-            x: x,
-            y: y,
-            search: search,
-        }
+        Self { x, y, search }
     }
 
     /// Raw Interpolator
@@ -40,7 +36,7 @@ impl Linear1D {
         let y_0 = self.y[i];
         let y_1 = self.y[i + 1];
 
-        return y_0 + ((y_1 - y_0) * ((x - x_0) / (x_1 - x_0)));
+        y_0 + ((y_1 - y_0) * ((x - x_0) / (x_1 - x_0)))
     }
 }
 
@@ -66,12 +62,12 @@ pub fn proof() {
     // Revisit this
     println!("Starting proof...");
 
-    let x_gt = Array1::linspace(0., 3.14159265 * 2., 1000);
+    let x_gt = Array1::linspace(0., std::f64::consts::PI * 2., 1000);
     let y_gt = x_gt.clone().map(|&x| f64::sin(x));
 
     // let iter_gt = std::iter::zip(x_gt, y_gt);
 
-    let x = Array1::linspace(0., 3.14159265 * 2., 7);
+    let x = Array1::linspace(0., std::f64::consts::PI * 2., 7);
 
     let mut my_interp = Linear1D::new(x_gt.clone(), y_gt.clone());
 
@@ -85,7 +81,7 @@ pub fn proof() {
         .set_label_area_size(LabelAreaPosition::Left, 40)
         .set_label_area_size(LabelAreaPosition::Bottom, 40)
         .caption("Linear 1D Interpolation Proof", ("sans-serif", 40))
-        .build_cartesian_2d(0f64..(3.14159265f64 * 2.), -1.0f64..1.0f64)
+        .build_cartesian_2d(0f64..(std::f64::consts::PI * 2.), -1.0f64..1.0f64)
         .unwrap();
 
     ctx.configure_mesh().draw().unwrap();
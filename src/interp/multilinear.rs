@@ -0,0 +1,133 @@
+use crate::interp::interpolator::InterpolateND;
+use crate::table::bisect_hunt::BisectHunt1D;
+use crate::table::search::Search;
+use ndarray::prelude::*;
+
+/// N Dimensional multilinear interpolator on a rectangular grid
+/// Blends the `2^N` corners of the cell containing `x`
+pub struct MultiLinearND {
+    axes: Vec<Array1<f64>>,      // x table for each axis
+    y: ArrayD<f64>,              // y table
+    searches: Vec<BisectHunt1D>, // Search Algorithm for each axis
+}
+
+impl MultiLinearND {
+    /// Constructor
+    /// #  Arguments
+    /// * `axes` - A monotonicly ascending or decending table for each axis
+    /// * `y` - A table of `f` with `y.shape()\[k\] == axes\[k\].len()`
+    /// # Returns
+    /// * `Self` - MultiLinearND Instaciate
+    pub fn new(axes: Vec<Array1<f64>>, y: ArrayD<f64>) -> Self {
+        assert_eq!(
+            axes.len(),
+            y.ndim(),
+            "MultiLinearND: one axis table is required per dimension of `y`"
+        );
+        assert!(
+            axes.iter().all(|x| x.len() >= 2),
+            "MultiLinearND: at least 2 points are required along each axis"
+        );
+        assert!(
+            axes.iter().zip(y.shape()).all(|(x, &n)| x.len() == n),
+            "MultiLinearND: `y` must have shape (axes[0].len(), ..., axes[N-1].len())"
+        );
+
+        // Instaciate search algorithms
+        let searches = axes.iter().map(|x| BisectHunt1D::new(x, 2)).collect();
+        Self { axes, y, searches }
+    }
+
+    /// Number of dimensions
+    pub fn ndim(&self) -> usize {
+        self.axes.len()
+    }
+}
+
+impl InterpolateND for MultiLinearND {
+    type YDim = IxDyn;
+
+    type Dtype = f64;
+
+    type X<'a> = &'a [f64];
+    type IndX = Vec<usize>;
+
+    type Y = f64;
+    type IndY = IxDyn;
+
+    /// Interpolation
+    /// #  Arguments
+    /// * `x` - The point `\[x0, ..., x(N-1)\]` for which `f(x)` is being approximated
+    /// # Returns
+    /// * `y : f64` - An approximation of `f(x)`
+    fn interpolate(&mut self, x: Self::X<'_>) -> Self::Y {
+        let nd = self.ndim();
+
+        assert_eq!(
+            x.len(),
+            nd,
+            "MultiLinearND: `x` must have one coordinate per axis"
+        );
+
+        // Get indecies and fractional positions within the cell
+        let mut lo = vec![0; nd];
+        let mut t = vec![0.0; nd];
+        for k in 0..nd {
+            let axis = &self.axes[k];
+            let i = self.searches[k].locate(axis, x[k]);
+            lo[k] = i;
+            t[k] = (x[k] - axis[i]) / (axis[i + 1] - axis[i]);
+        }
+
+        // Evaluate. Bit k of `corner` selects the upper end of axis k
+        let mut index = IxDyn(&lo);
+        let mut y = 0.0;
+        for corner in 0..1usize << nd {
+            let mut w = 1.0;
+            for k in 0..nd {
+                if corner >> k & 1 == 1 {
+                    index[k] = lo[k] + 1;
+                    w *= t[k];
+                } else {
+                    index[k] = lo[k];
+                    w *= 1.0 - t[k];
+                }
+            }
+            y += w * self.y[&index];
+        }
+
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::grid;
+
+    fn trilinear(x: &[f64]) -> f64 {
+        1.0 + x[0] - 2.0 * x[1] + 0.5 * x[2] + x[0] * x[1] - 3.0 * x[1] * x[2]
+            + 2.0 * x[0] * x[1] * x[2]
+    }
+
+    #[test]
+    fn reproduces_trilinear_data() {
+        let (x1, x2) = grid();
+        let axes = vec![x1, x2, arr1(&[2.0, 1.0, 0.0])];
+        let shape = axes.iter().map(|x| x.len()).collect::<Vec<_>>();
+        let y = ArrayD::from_shape_fn(IxDyn(&shape), |index| {
+            trilinear(&[axes[0][index[0]], axes[1][index[1]], axes[2][index[2]]])
+        });
+        let mut interp = MultiLinearND::new(axes, y);
+        assert_eq!(interp.ndim(), 3);
+
+        for &x0 in Array1::linspace(-1., 2., 7).iter() {
+            for &x1 in Array1::linspace(0., 1.5, 7).iter() {
+                for &x2 in Array1::linspace(0., 2., 7).iter() {
+                    let x = [x0, x1, x2];
+                    assert!((interp.interpolate(&x) - trilinear(&x)).abs() < 1e-12);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use crate::interp::hermite::hermite_cubic;
+use crate::interp::interpolator::Interpolate1D;
+use crate::table::bisect_hunt::BisectHunt1D;
+use crate::table::search::Search;
+use ndarray::prelude::*;
+
+/// 1 Dimensional monotone piecewise cubic Hermite interpolator (PCHIP)
+/// Based on the Fritsch-Carlson / Fritsch-Butland derivative estimates
+/// Monotone data gives a monotone interpolant, with no overshoot
+pub struct Pchip1D {
+    x: Array1<f64>,       // x  table
+    y: Array1<f64>,       // y  table
+    d: Array1<f64>,       // y' table
+    search: BisectHunt1D, // Search Algorithm
+}
+
+impl Pchip1D {
+    /// Constructor
+    /// #  Arguments
+    /// * `x` - A monotonicly ascending or decending table of `x\[0\], ..., x\[n-1\]`
+    /// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
+    /// # Returns
+    /// * `Self` - Pchip1D Instaciate
+    pub fn new(x: Array1<f64>, y: Array1<f64>) -> Self {
+        assert_eq!(
+            x.len(),
+            y.len(),
+            "Pchip1D: `x` and `y` must have equal length"
+        );
+        assert!(x.len() >= 2, "Pchip1D: at least 2 points are required");
+
+        let search = BisectHunt1D::new(&x, 2);
+        let d = Self::set_d(&x, &y);
+
+        Self { x, y, d, search }
+    }
+
+    /// Shape-preserving derivative estimates at the knots
+    fn set_d(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
+        let n = x.len();
+
+        let h = Array1::from_shape_fn(n - 1, |k| x[k + 1] - x[k]);
+        let delta = Array1::from_shape_fn(n - 1, |k| (y[k + 1] - y[k]) / h[k]);
+
+        if n == 2 {
+            return Array1::from_elem(n, delta[0]);
+        }
+
+        let mut d = Array1::<f64>::zeros([n]);
+
+        // Interior: weighted harmonic mean of the neighbouring slopes, zero at extrema
+        for k in 1..n - 1 {
+            if delta[k - 1] * delta[k] > 0.0 {
+                let w1 = 2.0 * h[k] + h[k - 1];
+                let w2 = h[k] + 2.0 * h[k - 1];
+                d[k] = (w1 + w2) / (w1 / delta[k - 1] + w2 / delta[k]);
+            }
+        }
+
+        // Ends: one-sided three point estimates, limited to preserve shape
+        d[0] = end_slope(h[0], h[1], delta[0], delta[1]);
+        d[n - 1] = end_slope(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+
+        d
+    }
+}
+
+/// Shape-preserving one-sided three point derivative estimate at an end knot
+/// #  Arguments
+/// * `h0` - Width of the end interval
+/// * `h1` - Width of the next interval in
+/// * `delta0` - Slope of the end interval
+/// * `delta1` - Slope of the next interval in
+fn end_slope(h0: f64, h1: f64, delta0: f64, delta1: f64) -> f64 {
+    let d = ((2.0 * h0 + h1) * delta0 - h0 * delta1) / (h0 + h1);
+
+    if d.signum() != delta0.signum() {
+        0.0
+    } else if delta0.signum() != delta1.signum() && d.abs() > 3.0 * delta0.abs() {
+        3.0 * delta0
+    } else {
+        d
+    }
+}
+
+impl Interpolate1D for Pchip1D {
+    type Dtype = f64;
+    type Data = Array1<Self::Dtype>;
+    type Index = usize;
+
+    /// Interpolation
+    /// #  Arguments
+    /// * `x` - The x value for which `f(x)` is being approximated
+    /// # Returns
+    /// * `y : f64` - An approximation of `f(x)`
+    fn interpolate(&mut self, x: Self::Dtype) -> Self::Dtype {
+        // Get indecies
+        let i = self.search.locate(&self.x, x);
+
+        // Evaluate
+        hermite_cubic(
+            x,
+            self.x[i],
+            self.x[i + 1],
+            self.y[i],
+            self.y[i + 1],
+            self.d[i],
+            self.d[i + 1],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{non_decreasing, steps};
+
+    #[test]
+    fn stays_monotone_on_step_data() {
+        let (x, y) = steps();
+        let mut pchip = Pchip1D::new(x, y);
+
+        let samples = Array1::linspace(0., 7., 701).mapv(|x| pchip.interpolate(x));
+        assert!(non_decreasing(&samples));
+    }
+
+    #[test]
+    fn reproduces_knots() {
+        let (x, y) = steps();
+        let mut pchip = Pchip1D::new(x.clone(), y.clone());
+
+        for (&x_k, &y_k) in x.iter().zip(y.iter()) {
+            assert!((pchip.interpolate(x_k) - y_k).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn descending_table_matches_ascending() {
+        let (x, y) = steps();
+        let mut ascending = Pchip1D::new(x.clone(), y.clone());
+        let mut descending =
+            Pchip1D::new(x.slice(s![..;-1]).to_owned(), y.slice(s![..;-1]).to_owned());
+
+        for &x_q in Array1::linspace(0., 7., 141).iter() {
+            assert!((ascending.interpolate(x_q) - descending.interpolate(x_q)).abs() < 1e-12);
+        }
+    }
+}
@@ -3,13 +3,33 @@ use crate::table::bisect_hunt::BisectHunt1D;
 use crate::table::search::Search;
 use ndarray::prelude::*;
 use plotters::prelude::*;
+use std::fmt;
+
+/// Error returned when the polynomial degree is too large for the table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDegree {
+    pub m: usize, // Requested degree
+    pub n: usize, // Table length
+}
+
+impl fmt::Display for InvalidDegree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "polynomial degree {} must be lesser than the table length {}",
+            self.m, self.n
+        )
+    }
+}
+
+impl std::error::Error for InvalidDegree {}
 
 /// 1 Dimensional polynomial interpolator
 /// Based on Neville's Algorithm
 pub struct Poly1D {
     x: Array1<f64>,       // x table
     y: Array1<f64>,       // y table
-    m: usize,             // Polynomial degree / bracket size
+    m: usize,             // Polynomial degree. Bracket size is m + 1
     search: BisectHunt1D, // Search Algorithm
 }
 
@@ -20,40 +40,87 @@ impl Poly1D {
     /// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
     /// * `m` - Polynomial deree. Must be lesser than the length of `x`
     /// # Returns
-    /// * `Self` - Poly1D Instaciate
-    pub fn new(x: Array1<f64>, y: Array1<f64>, m: usize) -> Self {
-        // Instaciate search algorithm
-        let search = BisectHunt1D::new(&x, m);
-        Self {
-            x: x,
-            y: y,
-            m: m,
-            search: search,
+    /// * `Result<Self, InvalidDegree>` - Poly1D Instaciate, or `Err` if `m` is too large
+    pub fn new(x: Array1<f64>, y: Array1<f64>, m: usize) -> Result<Self, InvalidDegree> {
+        if m >= x.len() {
+            return Err(InvalidDegree { m, n: x.len() });
         }
+
+        // Instaciate search algorithm over an m + 1 point bracket
+        let search = BisectHunt1D::new(&x, m + 1);
+        Ok(Self { x, y, m, search })
     }
 
     /// Raw Interpolator
     /// #  Arguments
     /// * `x` - The x value for which `f(x)` is being approximated
-    /// * `i` - The index for which `x\[i\] <= x <= x\[i + 1\]` is guarenteed
+    /// * `i` - The first index of the bracket `x\[i\], ..., x\[i + m\]`
     /// # Returns
-    /// * `y : f64` - An approximation of `f(x)`
-    pub fn raw_interpolate(&mut self, x: f64, i: usize) -> f64 {
-        let xa = self.x.slice(s![i..i + self.m + 1]);
-        let mut ya = self.y.slice_mut(s![i..i + self.m + 1]).to_owned();
-
-        for m in 1..self.m + 1 {
-            for n in 0..self.m - m {
-                let p_ip1_j = ya[n + 1];
-                let p_i_jm1 = ya[n];
-                let x_i = xa[n];
-                let x_j = xa[n + m];
-                ya[n] = (((x - x_i) * p_ip1_j) - ((x - x_j) * p_i_jm1)) / (x_j - x_i);
+    /// * `(y, dy) : (f64, f64)` - An approximation of `f(x)` and an estimate of its error
+    pub fn raw_interpolate(&self, x: f64, i: usize) -> (f64, f64) {
+        let mm = self.m + 1;
+        neville(self.x.slice(s![i..i + mm]), self.y.slice(s![i..i + mm]), x)
+    }
+}
+
+/// Neville's Algorithm through every point of the bracket
+/// #  Arguments
+/// * `xa` - The bracket `x\[i\], ..., x\[i + m\]`
+/// * `ya` - The matching `f(x\[i\]), ..., f(x\[i + m\])`
+/// * `x` - The x value for which `f(x)` is being approximated
+/// # Returns
+/// * `(y, dy) : (f64, f64)` - An approximation of `f(x)` and an estimate of its error
+pub(crate) fn neville(xa: ArrayView1<f64>, ya: ArrayView1<f64>, x: f64) -> (f64, f64) {
+    let mm = xa.len();
+
+    // Corrections to the tableau, going up (c) and down (d)
+    let mut c = ya.to_owned();
+    let mut d = ya.to_owned();
+
+    // Find the closest table entry
+    let mut ns = 0;
+    let mut dif = (x - xa[0]).abs();
+    for (j, &x_j) in xa.iter().enumerate() {
+        let dift = (x - x_j).abs();
+        if dift < dif {
+            ns = j;
+            dif = dift;
+        }
+    }
+
+    // Initial approximation, then walk the tableau towards the closest entry
+    let mut y = ya[ns];
+    let mut ns = ns as isize - 1;
+    let mut dy = 0.0;
+
+    for m in 1..mm {
+        for n in 0..mm - m {
+            let ho = xa[n] - x;
+            let hp = xa[n + m] - x;
+            let w = c[n + 1] - d[n];
+
+            // x's must be unique
+            let den = ho - hp;
+            if den == 0.0 {
+                panic!("Bad input to routine polynomial interpolation.")
             }
+
+            let den = w / den;
+            d[n] = hp * den;
+            c[n] = ho * den;
         }
 
-        return ya[0];
+        // Take the straightest path through the tableau. The last step is the error estimate
+        dy = if 2 * (ns + 1) < (mm - m) as isize {
+            c[(ns + 1) as usize]
+        } else {
+            ns -= 1;
+            d[(ns + 1) as usize]
+        };
+        y += dy;
     }
+
+    (y, dy)
 }
 
 impl Interpolate1D for Poly1D {
@@ -70,6 +137,19 @@ impl Interpolate1D for Poly1D {
         // Get indecies
         let i = self.search.locate(&self.x, x);
 
+        // Evaluate
+        self.raw_interpolate(x, i).0
+    }
+
+    /// Interpolation with error estimate
+    /// #  Arguments
+    /// * `x` - The x value for which `f(x)` is being approximated
+    /// # Returns
+    /// * `(y, dy) : (f64, f64)` - An approximation of `f(x)` and an estimate of its error
+    fn interpolate_with_error(&mut self, x: Self::Dtype) -> (Self::Dtype, Self::Dtype) {
+        // Get indecies
+        let i = self.search.locate(&self.x, x);
+
         // Evaluate
         self.raw_interpolate(x, i)
     }
@@ -79,14 +159,14 @@ pub fn proof() {
     // Revisit this
     println!("Starting proof...");
 
-    let x_gt = Array1::linspace(0., 3.14159265 * 2., 1000);
+    let x_gt = Array1::linspace(0., std::f64::consts::PI * 2., 1000);
     let y_gt = x_gt.clone().map(|&x| f64::sin(x));
 
     // let iter_gt = std::iter::zip(x_gt, y_gt);
 
-    let x = Array1::linspace(0., 3.14159265 * 2., 7);
+    let x = Array1::linspace(0., std::f64::consts::PI * 2., 7);
 
-    let mut my_interp = Poly1D::new(x_gt.clone(), y_gt.clone(), 4);
+    let mut my_interp = Poly1D::new(x_gt.clone(), y_gt.clone(), 4).unwrap();
 
     let y = x.clone().map(|&x| my_interp.interpolate(x));
 
@@ -97,7 +177,7 @@ pub fn proof() {
         .set_label_area_size(LabelAreaPosition::Left, 40)
         .set_label_area_size(LabelAreaPosition::Bottom, 40)
         .caption("Linear 1D Interpolation Proof", ("sans-serif", 40))
-        .build_cartesian_2d(0f64..(3.14159265f64 * 2.), -1.0f64..1.0f64)
+        .build_cartesian_2d(0f64..(std::f64::consts::PI * 2.), -1.0f64..1.0f64)
         .unwrap();
 
     ctx.configure_mesh().draw().unwrap();
@@ -116,3 +196,68 @@ pub fn proof() {
 
     println!("Proof complete.");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Exact polynomial of degree m with non-trivial coefficients
+    fn poly(m: usize, x: f64) -> f64 {
+        (0..=m)
+            .rev()
+            .fold(0., |acc, k| acc * x + (k as f64 + 1.) * 0.5)
+    }
+
+    #[test]
+    fn reproduces_polynomials_of_each_degree() {
+        let x = Array1::linspace(-1., 2., 12);
+
+        for m in 0..8 {
+            let y = x.mapv(|x| poly(m, x));
+            let mut interp = Poly1D::new(x.clone(), y, m).unwrap();
+
+            // Ends, interior and exact nodes
+            for &x_q in Array1::linspace(-1., 2., 37).iter() {
+                let (y_q, _) = interp.interpolate_with_error(x_q);
+                assert!(
+                    (y_q - poly(m, x_q)).abs() < 1e-10,
+                    "degree {m} at x = {x_q}: {y_q} != {}",
+                    poly(m, x_q)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reproduces_polynomials_on_descending_tables() {
+        let x = Array1::linspace(3., -3., 9);
+        let y = x.mapv(|x| poly(4, x));
+        let mut interp = Poly1D::new(x, y, 4).unwrap();
+
+        for &x_q in Array1::linspace(-3., 3., 25).iter() {
+            assert!((interp.interpolate(x_q) - poly(4, x_q)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn error_estimate_vanishes_for_lower_degree_data() {
+        let x = Array1::linspace(0., 1., 10);
+        let y = x.mapv(|x| poly(2, x));
+        let mut interp = Poly1D::new(x, y, 4).unwrap();
+
+        let (_, dy) = interp.interpolate_with_error(0.37);
+        assert!(dy.abs() < 1e-12);
+    }
+
+    #[test]
+    fn rejects_degree_too_large_for_table() {
+        let x = Array1::linspace(0., 1., 5);
+        let y = x.clone();
+
+        assert_eq!(
+            Poly1D::new(x.clone(), y.clone(), 5).err(),
+            Some(InvalidDegree { m: 5, n: 5 })
+        );
+        assert!(Poly1D::new(x, y, 4).is_ok());
+    }
+}
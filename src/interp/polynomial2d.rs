@@ -0,0 +1,197 @@
+use crate::interp::interpolator::InterpolateND;
+use crate::interp::polynomial::neville;
+use crate::table::bisect_hunt::BisectHunt1D;
+use crate::table::search::Search;
+use ndarray::prelude::*;
+use std::fmt;
+
+/// Error returned when a polynomial degree is too large for its axis table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDegree2D {
+    pub axis: usize, // Offending axis. 0 for x1, 1 for x2
+    pub m: usize,    // Requested degree
+    pub n: usize,    // Table length along the axis
+}
+
+impl fmt::Display for InvalidDegree2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "polynomial degree {} along axis {} must be lesser than the table length {}",
+            self.m, self.axis, self.n
+        )
+    }
+}
+
+impl std::error::Error for InvalidDegree2D {}
+
+/// 2 Dimensional polynomial interpolator on a rectangular grid
+/// Successive Neville interpolations over the `(m + 1) x (n + 1)` block of the grid around `x`
+pub struct Poly2D {
+    x1: Array1<f64>,       // x1 table
+    x2: Array1<f64>,       // x2 table
+    y: Array2<f64>,        // y table
+    m: usize,              // Polynomial degree along x1. Bracket size is m + 1
+    n: usize,              // Polynomial degree along x2. Bracket size is n + 1
+    search1: BisectHunt1D, // Search Algorithm x1
+    search2: BisectHunt1D, // Search Algorithm x2
+}
+
+impl Poly2D {
+    /// Constructor
+    /// #  Arguments
+    /// * `x1` - A monotonicly ascending or decending table of `x1\[0\], ..., x1\[p-1\]`
+    /// * `x2` - A monotonicly ascending or decending table of `x2\[0\], ..., x2\[q-1\]`
+    /// * `y` - A `p x q` table of `f(x1\[i\], x2\[j\])`
+    /// * `m` - Polynomial degree along `x1`. Must be lesser than the length of `x1`
+    /// * `n` - Polynomial degree along `x2`. Must be lesser than the length of `x2`
+    /// # Returns
+    /// * `Result<Self, InvalidDegree2D>` - Poly2D Instaciate, or `Err` naming the axis whose degree is too large
+    pub fn new(
+        x1: Array1<f64>,
+        x2: Array1<f64>,
+        y: Array2<f64>,
+        m: usize,
+        n: usize,
+    ) -> Result<Self, InvalidDegree2D> {
+        assert_eq!(
+            y.dim(),
+            (x1.len(), x2.len()),
+            "Poly2D: `y` must have shape (x1.len(), x2.len())"
+        );
+
+        if m >= x1.len() {
+            return Err(InvalidDegree2D {
+                axis: 0,
+                m,
+                n: x1.len(),
+            });
+        }
+        if n >= x2.len() {
+            return Err(InvalidDegree2D {
+                axis: 1,
+                m: n,
+                n: x2.len(),
+            });
+        }
+
+        // Instaciate search algorithms over m + 1 and n + 1 point brackets
+        let search1 = BisectHunt1D::new(&x1, m + 1);
+        let search2 = BisectHunt1D::new(&x2, n + 1);
+        Ok(Self {
+            x1,
+            x2,
+            y,
+            m,
+            n,
+            search1,
+            search2,
+        })
+    }
+
+    /// Interpolation with error estimate
+    /// #  Arguments
+    /// * `x` - The point `\[x1, x2\]` for which `f(x1, x2)` is being approximated
+    /// # Returns
+    /// * `(y, dy) : (f64, f64)` - An approximation of `f(x1, x2)` and an estimate of its error.
+    ///   The error is that of the final interpolation along `x1`
+    pub fn interpolate_with_error(&mut self, x: [f64; 2]) -> (f64, f64) {
+        let [x1, x2] = x;
+        let (m, n) = (self.m, self.n);
+
+        // Get indecies
+        let i = self.search1.locate(&self.x1, x1);
+        let j = self.search2.locate(&self.x2, x2);
+
+        // Interpolate each row of the block along x2
+        let x2a = self.x2.slice(s![j..j + n + 1]);
+        let ymtmp = Array1::from_shape_fn(m + 1, |k| {
+            neville(x2a, self.y.slice(s![i + k, j..j + n + 1]), x2).0
+        });
+
+        // Interpolate the row results along x1
+        neville(self.x1.slice(s![i..i + m + 1]), ymtmp.view(), x1)
+    }
+}
+
+impl InterpolateND for Poly2D {
+    type YDim = Ix2;
+
+    type Dtype = f64;
+
+    type X<'a> = [f64; 2];
+    type IndX = [usize; 2];
+
+    type Y = f64;
+    type IndY = [usize; 2];
+
+    /// Interpolation
+    /// #  Arguments
+    /// * `x` - The point `\[x1, x2\]` for which `f(x1, x2)` is being approximated
+    /// # Returns
+    /// * `y : f64` - An approximation of `f(x1, x2)`
+    fn interpolate(&mut self, x: Self::X<'_>) -> Self::Y {
+        self.interpolate_with_error(x).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::grid;
+
+    fn bicubic(x1: f64, x2: f64) -> f64 {
+        (x1 * x1 * x1 - x1 + 2.0) * (2.0 * x2 * x2 * x2 + x2 * x2 - 1.0) + x1 * x2
+    }
+
+    #[test]
+    fn reproduces_bicubic_polynomial() {
+        let (x1, x2) = grid();
+        let y = Array2::from_shape_fn([x1.len(), x2.len()], |(i, j)| bicubic(x1[i], x2[j]));
+        let mut poly = Poly2D::new(x1, x2, y, 3, 3).unwrap();
+
+        for &x1 in Array1::linspace(-1., 2., 31).iter() {
+            for &x2 in Array1::linspace(0., 1.5, 16).iter() {
+                assert!((poly.interpolate([x1, x2]) - bicubic(x1, x2)).abs() < 1e-11);
+            }
+        }
+    }
+
+    #[test]
+    fn error_estimate_vanishes_for_lower_degree_data() {
+        let (x1, x2) = grid();
+        let y = Array2::from_shape_fn([x1.len(), x2.len()], |(i, j)| bicubic(x1[i], x2[j]));
+        let mut poly = Poly2D::new(x1, x2, y, 4, 3).unwrap();
+
+        for &x1 in Array1::linspace(-1., 2., 31).iter() {
+            for &x2 in Array1::linspace(0., 1.5, 16).iter() {
+                let (y, dy) = poly.interpolate_with_error([x1, x2]);
+                assert!((y - bicubic(x1, x2)).abs() < 1e-11);
+                assert!(dy.abs() < 1e-11);
+            }
+        }
+    }
+
+    #[test]
+    fn degree_must_fit_each_axis() {
+        let (x1, x2) = grid();
+        let y = Array2::<f64>::zeros([x1.len(), x2.len()]);
+
+        assert_eq!(
+            Poly2D::new(x1.clone(), x2.clone(), y.clone(), 5, 2).err(),
+            Some(InvalidDegree2D {
+                axis: 0,
+                m: 5,
+                n: 5
+            })
+        );
+        assert_eq!(
+            Poly2D::new(x1, x2, y, 2, 4).err(),
+            Some(InvalidDegree2D {
+                axis: 1,
+                m: 4,
+                n: 4
+            })
+        );
+    }
+}
@@ -1,8 +1,306 @@
 use crate::interp::polynomial::Poly1D;
+use crate::linalg::vander::vander;
 use ndarray::{prelude::*, Zip};
-use std::ops::SubAssign;
+use num_complex::Complex64;
+use std::fmt;
+use std::ops::{Add, Mul, SubAssign};
 
-fn polcoe_0(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
+/// Error returned when Laguerre's method fails to converge on a root
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LaguerreNoConvergence;
+
+impl fmt::Display for LaguerreNoConvergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "too many iterations in laguer")
+    }
+}
+
+impl std::error::Error for LaguerreNoConvergence {}
+
+/// Polynomial `c\[0\] + c\[1\] x + ... + c\[n\] x^n` held by its coefficients
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    coef: Array1<f64>, // Coefficients, ascending powers of x
+}
+
+impl Polynomial {
+    /// Constructor
+    /// #  Arguments
+    /// * `coef` - Coefficients `c\[0\], ..., c\[n\]` in ascending powers of `x`
+    /// # Returns
+    /// * `Self` - Polynomial Instaciate
+    pub fn new(coef: Array1<f64>) -> Self {
+        if coef.is_empty() {
+            return Self { coef: arr1(&[0.]) };
+        }
+        Self { coef }
+    }
+
+    /// Coefficients in ascending powers of `x`
+    pub fn coefficients(&self) -> &Array1<f64> {
+        &self.coef
+    }
+
+    /// Degree, ignoring any zero leading coefficients
+    pub fn degree(&self) -> usize {
+        self.coef.iter().rposition(|&c| c != 0.0).unwrap_or(0)
+    }
+
+    /// Evaluates `p(x)` by Horner's rule
+    pub fn eval(&self, x: f64) -> f64 {
+        self.coef.iter().rev().fold(0., |acc, &c| acc * x + c)
+    }
+
+    /// Evaluates `p(x)` and its first `nd` derivatives
+    /// #  Arguments
+    /// * `x` - The point of evaluation
+    /// * `nd` - Number of derivatives
+    /// # Returns
+    /// * `pd : Array1<f64>` - `p(x), p'(x), ..., p^(nd)(x)`
+    pub fn ddpoly(&self, x: f64, nd: usize) -> Array1<f64> {
+        let nc = self.coef.len() - 1;
+
+        let mut pd = Array1::<f64>::zeros([nd + 1]);
+        pd[0] = self.coef[nc];
+
+        for i in (0..nc).rev() {
+            for j in (1..=nd.min(nc - i)).rev() {
+                pd[j] = pd[j] * x + pd[j - 1];
+            }
+            pd[0] = pd[0] * x + self.coef[i];
+        }
+
+        // Convert the Taylor coefficients to derivatives
+        let mut cnst = 1.0;
+        for i in 2..=nd {
+            cnst *= i as f64;
+            pd[i] *= cnst;
+        }
+
+        pd
+    }
+
+    /// Derivative `p'(x)`
+    pub fn derivative(&self) -> Polynomial {
+        let n = self.coef.len();
+        if n == 1 {
+            return Polynomial::new(arr1(&[0.]));
+        }
+        Polynomial::new(Array1::from_shape_fn(n - 1, |k| {
+            (k + 1) as f64 * self.coef[k + 1]
+        }))
+    }
+
+    /// Antiderivative, with zero constant of integration
+    pub fn integral(&self) -> Polynomial {
+        let n = self.coef.len();
+        Polynomial::new(Array1::from_shape_fn(n + 1, |k| match k {
+            0 => 0.,
+            _ => self.coef[k - 1] / k as f64,
+        }))
+    }
+
+    /// Definite integral of `p(x)` from `a` to `b`
+    pub fn integrate(&self, a: f64, b: f64) -> f64 {
+        let p = self.integral();
+        p.eval(b) - p.eval(a)
+    }
+
+    /// Synthetic division by `v`
+    /// #  Arguments
+    /// * `v` - The divisor. Must not be identically zero
+    /// # Returns
+    /// * `(q, r) : (Polynomial, Polynomial)` - Quotient and remainder, `self = q v + r`
+    pub fn poldiv(&self, v: &Polynomial) -> (Polynomial, Polynomial) {
+        let n = self.degree();
+        let nv = v.degree();
+
+        if v.coef[nv] == 0.0 {
+            panic!("Division by zero polynomial in routine poldiv.")
+        }
+
+        if n < nv {
+            return (Polynomial::new(arr1(&[0.])), self.clone());
+        }
+
+        let mut r = self.coef.slice(s![..=n]).to_owned();
+        let mut q = Array1::<f64>::zeros([n - nv + 1]);
+
+        for k in (0..=n - nv).rev() {
+            q[k] = r[nv + k] / v.coef[nv];
+            for j in (k..nv + k).rev() {
+                r[j] -= q[k] * v.coef[j - k];
+            }
+        }
+
+        // Remainder has degree below nv
+        let r = match nv {
+            0 => arr1(&[0.]),
+            _ => r.slice(s![..nv]).to_owned(),
+        };
+
+        (Polynomial::new(q), Polynomial::new(r))
+    }
+
+    /// All roots, real and complex, by Laguerre's method with deflation then polishing
+    /// # Returns
+    /// * `Result<Vec<Complex64>, LaguerreNoConvergence>` - The `degree()` roots, sorted by real part
+    pub fn roots(&self) -> Result<Vec<Complex64>, LaguerreNoConvergence> {
+        const EPS: f64 = 1.0e-14;
+
+        let m = self.degree();
+        let a: Vec<Complex64> = self
+            .coef
+            .iter()
+            .take(m + 1)
+            .map(|&c| Complex64::new(c, 0.))
+            .collect();
+
+        let mut ad = a.clone();
+        let mut roots = vec![Complex64::new(0., 0.); m];
+
+        for j in (0..m).rev() {
+            // Root of the deflated polynomial
+            let mut x = Complex64::new(0., 0.);
+            laguer(&ad[..j + 2], &mut x)?;
+            if x.im.abs() <= 2.0 * EPS * x.re.abs() {
+                x = Complex64::new(x.re, 0.);
+            }
+            roots[j] = x;
+
+            // Forward deflation
+            let mut b = ad[j + 1];
+            for jj in (0..=j).rev() {
+                let c = ad[jj];
+                ad[jj] = b;
+                b = x * b + c;
+            }
+        }
+
+        // Polish against the undeflated polynomial
+        for root in roots.iter_mut() {
+            laguer(&a, root)?;
+        }
+
+        roots.sort_by(|r1, r2| r1.re.total_cmp(&r2.re));
+
+        Ok(roots)
+    }
+}
+
+impl Add for &Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, rhs: &Polynomial) -> Polynomial {
+        let n = self.coef.len().max(rhs.coef.len());
+        Polynomial::new(Array1::from_shape_fn(n, |k| {
+            self.coef.get(k).unwrap_or(&0.) + rhs.coef.get(k).unwrap_or(&0.)
+        }))
+    }
+}
+
+impl Add for Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, rhs: Polynomial) -> Polynomial {
+        &self + &rhs
+    }
+}
+
+impl Mul for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: &Polynomial) -> Polynomial {
+        let mut coef = Array1::<f64>::zeros([self.coef.len() + rhs.coef.len() - 1]);
+        for (i, &a) in self.coef.iter().enumerate() {
+            coef.slice_mut(s![i..i + rhs.coef.len()])
+                .scaled_add(a, &rhs.coef);
+        }
+        Polynomial::new(coef)
+    }
+}
+
+impl Mul for Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: Polynomial) -> Polynomial {
+        &self * &rhs
+    }
+}
+
+/// Improves a root estimate `x` of the polynomial with complex coefficients `a` by Laguerre's method
+/// #  Arguments
+/// * `a` - Coefficients in ascending powers of `x`
+/// * `x` - Initial root estimate. Replaced by the root
+fn laguer(a: &[Complex64], x: &mut Complex64) -> Result<(), LaguerreNoConvergence> {
+    const MR: usize = 8;
+    const MT: usize = 10;
+    const MAXIT: usize = MT * MR;
+
+    // Fractions used to break limit cycles
+    const FRAC: [f64; MR + 1] = [0.0, 0.5, 0.25, 0.75, 0.13, 0.38, 0.62, 0.88, 1.0];
+
+    let m = a.len() - 1;
+    let mf = m as f64;
+
+    for iter in 1..=MAXIT {
+        // Evaluate the polynomial and its first two derivatives, with a roundoff bound
+        let mut b = a[m];
+        let mut err = b.norm();
+        let mut d = Complex64::new(0., 0.);
+        let mut f = Complex64::new(0., 0.);
+        let abx = x.norm();
+        for j in (0..m).rev() {
+            f = *x * f + d;
+            d = *x * d + b;
+            b = *x * b + a[j];
+            err = b.norm() + abx * err;
+        }
+        err *= f64::EPSILON;
+
+        // On the root
+        if b.norm() <= err {
+            return Ok(());
+        }
+
+        // Laguerre's step
+        let g = d / b;
+        let g2 = g * g;
+        let h = g2 - 2.0 * f / b;
+        let sq = ((mf - 1.0) * (mf * h - g2)).sqrt();
+        let gp = g + sq;
+        let gm = g - sq;
+        let (abp, abm) = (gp.norm(), gm.norm());
+        let gp = if abp < abm { gm } else { gp };
+        let dx = if abp.max(abm) > 0.0 {
+            mf / gp
+        } else {
+            Complex64::from_polar(1.0 + abx, iter as f64)
+        };
+
+        let x1 = *x - dx;
+        if *x == x1 {
+            return Ok(());
+        }
+
+        // Take a fractional step every so often to break any limit cycle
+        if iter % MT != 0 {
+            *x = x1;
+        } else {
+            *x -= FRAC[iter / MT] * dx;
+        }
+    }
+
+    Err(LaguerreNoConvergence)
+}
+
+/// Coefficients of the polynomial through a table of points, in `O(n^2)` operations
+/// #  Arguments
+/// * `x` - A table of distinct `x\[0\], ..., x\[n-1\]`
+/// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
+/// # Returns
+/// * `p : Polynomial` - The interpolating polynomial of degree `n-1`
+pub fn polcoe_0(x: &Array1<f64>, y: &Array1<f64>) -> Polynomial {
     let n = x.len();
     let mut s = Array1::<f64>::zeros([n]);
     let mut coef = Array1::<f64>::zeros([n]);
@@ -30,10 +328,17 @@ fn polcoe_0(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
         }
     }
 
-    coef
+    Polynomial::new(coef)
 }
 
-fn polcoe_1(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
+/// As `polcoe_0`, expressed with ndarray operations
+/// Same algorithm and results, so it is not offered through `CoefMethod`
+/// #  Arguments
+/// * `x` - A table of distinct `x\[0\], ..., x\[n-1\]`
+/// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
+/// # Returns
+/// * `p : Polynomial` - The interpolating polynomial of degree `n-1`
+pub fn polcoe_1(x: &Array1<f64>, y: &Array1<f64>) -> Polynomial {
     let shape = x.raw_dim();
     let n = x.len();
 
@@ -56,7 +361,7 @@ fn polcoe_1(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
     let k = Array1::linspace((n - 1) as f64, 1., n - 1);
     let s_k = s.slice(s![1..n;-1]);
 
-    phi.zip_mut_with(&x, |p, x_j| {
+    phi.zip_mut_with(x, |p, x_j| {
         *p = (&k * &s_k).iter().fold(*p, |acc, _a| _a + x_j * acc)
     });
 
@@ -74,10 +379,17 @@ fn polcoe_1(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
         })
     });
 
-    coef
+    Polynomial::new(coef)
 }
 
-fn polcof(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
+/// Coefficients of the polynomial through a table of points by repeated extrapolation to zero
+/// `O(n^3)`, but more stable than `polcoe_0` when the table is far from the origin
+/// #  Arguments
+/// * `x` - A table of distinct `x\[0\], ..., x\[n-1\]`
+/// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
+/// # Returns
+/// * `p : Polynomial` - The interpolating polynomial of degree `n-1`
+pub fn polcof(x: &Array1<f64>, y: &Array1<f64>) -> Polynomial {
     let n = x.len();
     let mut coef = Array1::<f64>::from_elem([n], 0.);
 
@@ -85,9 +397,10 @@ fn polcof(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
     let mut ya = y.clone().to_owned();
 
     for j in 0..n {
-        let mut interp = Poly1D::new(xa.clone().to_owned(), ya.clone().to_owned(), n - j - 1);
+        // Degree n - j - 1 through the first n - j points, which always fit the table
+        let interp = Poly1D::new(xa.clone(), ya.clone(), n - j - 1).unwrap();
 
-        coef[j] = interp.raw_interpolate(0., 0);
+        coef[j] = interp.raw_interpolate(0., 0).0;
 
         let mut k = None;
         let mut xmin = 1.0e99;
@@ -108,7 +421,63 @@ fn polcof(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
         }
     }
 
-    coef
+    Polynomial::new(coef)
+}
+
+/// Coefficients of the polynomial through a table of points, by `n` solves of the transposed Vandermonde system
+/// Row `k` of the inverse Vandermonde matrix is the `vander` solution for the unit vector `e_k`. `O(n^3)`
+/// #  Arguments
+/// * `x` - A table of distinct `x\[0\], ..., x\[n-1\]`
+/// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
+/// # Returns
+/// * `p : Polynomial` - The interpolating polynomial of degree `n-1`
+pub fn polcoe_vander(x: &Array1<f64>, y: &Array1<f64>) -> Polynomial {
+    let n = x.len();
+    let mut e = Array1::<f64>::zeros([n]);
+    let mut coef = Array1::<f64>::zeros([n]);
+
+    for k in 0..n {
+        e[k] = 1.0;
+        let row = vander(x, &e).expect("polcoe_vander: `x` must be distinct");
+        e[k] = 0.0;
+
+        coef[k] = row.dot(y);
+    }
+
+    Polynomial::new(coef)
+}
+
+/// Algorithm used to find the coefficients of an interpolating polynomial
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoefMethod {
+    Polcoe, // O(n^2) Vandermonde solve via the master polynomial
+    Polcof, // O(n^3) repeated Neville extrapolation to zero. More stable
+    Vander, // O(n^3) row by row inverse of the Vandermonde matrix via linalg::vander
+}
+
+/// Coefficients of the polynomial through a table of points
+/// #  Arguments
+/// * `x` - A table of distinct `x\[0\], ..., x\[n-1\]`
+/// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
+/// * `method` - Algorithm used to solve for the coefficients
+/// # Returns
+/// * `p : Polynomial` - The interpolating polynomial of degree `n-1`. Zero for an empty table
+pub fn poly_coefficients(x: &Array1<f64>, y: &Array1<f64>, method: CoefMethod) -> Polynomial {
+    assert_eq!(
+        x.len(),
+        y.len(),
+        "poly_coefficients: `x` and `y` must have equal length"
+    );
+
+    if x.is_empty() {
+        return Polynomial::new(Array1::zeros([0]));
+    }
+
+    match method {
+        CoefMethod::Polcoe => polcoe_0(x, y),
+        CoefMethod::Polcof => polcof(x, y),
+        CoefMethod::Vander => polcoe_vander(x, y),
+    }
 }
 
 pub fn proof() {
@@ -119,15 +488,17 @@ pub fn proof() {
     let y = Array1::linspace(400., 500., 10);
 
     // Calculate coeficients
-    let coefs_1_0 = polcoe_0(&x.clone().to_owned(), &y.clone().to_owned());
-    let coefs_1_1 = polcoe_1(&x.clone().to_owned(), &y.clone().to_owned());
-    let coefs_2 = polcof(&x.clone().to_owned(), &y.clone().to_owned());
+    let coefs_1_0 = poly_coefficients(&x, &y, CoefMethod::Polcoe);
+    let coefs_1_1 = polcoe_1(&x, &y);
+    let coefs_2 = poly_coefficients(&x, &y, CoefMethod::Polcof);
+    let coefs_3 = poly_coefficients(&x, &y, CoefMethod::Vander);
 
     // Define test routine
-    fn test(coefs: Array1<f64>, x: &Array1<f64>, y: &Array1<f64>) {
+    fn test(p: Polynomial, x: &Array1<f64>, y: &Array1<f64>) {
+        let coefs = p.coefficients();
         let _x = Array2::<f64>::from_shape_fn([x.len(), x.len()], |(i, j)| x[i].powi(j as i32));
 
-        let err = 100. * (y - _x.dot(&coefs)).dot(&(y - _x.dot(&coefs))) / (x.len() as f64);
+        let err = 100. * (y - _x.dot(coefs)).dot(&(y - _x.dot(coefs))) / (x.len() as f64);
         println!("Error:\t{err:.3} %");
     }
 
@@ -135,9 +506,108 @@ pub fn proof() {
     println!("\npolcoe_0");
     test(coefs_1_0, &x, &y);
 
-    println!("\npolcoe_0");
+    println!("\npolcoe_1");
     test(coefs_1_1, &x, &y);
 
     println!("\npolcof");
     test(coefs_2, &x, &y);
+
+    println!("\nvander");
+    test(coefs_3, &x, &y);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::max_abs;
+
+    const METHODS: [CoefMethod; 3] = [CoefMethod::Polcoe, CoefMethod::Polcof, CoefMethod::Vander];
+
+    #[test]
+    fn reproduces_cubic_coefficients() {
+        // 2 - x + 0.5 x^2 + 3 x^3 through five points, so the quartic term vanishes
+        let coef = arr1(&[2., -1., 0.5, 3., 0.]);
+        let x = arr1(&[-1.5, -0.5, 0.25, 1., 2.]);
+        let y = x.mapv(|x| 2. - x + 0.5 * x * x + 3. * x * x * x);
+
+        for method in METHODS {
+            let p = poly_coefficients(&x, &y, method);
+            assert!(max_abs(&(p.coefficients() - &coef)) < 1e-12, "{method:?}");
+        }
+    }
+
+    #[test]
+    fn empty_table_gives_zero_polynomial() {
+        let x = Array1::<f64>::zeros([0]);
+
+        for method in METHODS {
+            let p = poly_coefficients(&x, &x, method);
+            assert_eq!(p.coefficients(), arr1(&[0.]), "{method:?}");
+        }
+    }
+
+    #[test]
+    fn eval_add_and_mul() {
+        let p = Polynomial::new(arr1(&[1., 1.]));
+        let q = Polynomial::new(arr1(&[-1., 1.]));
+
+        assert_eq!((&p * &q).coefficients(), arr1(&[-1., 0., 1.]));
+        assert_eq!((&p + &q).coefficients(), arr1(&[0., 2.]));
+        assert_eq!((p * q).eval(3.), 8.);
+    }
+
+    #[test]
+    fn ddpoly_matches_repeated_derivative() {
+        let p = Polynomial::new(arr1(&[0.5, -2., 1., 3., -0.25, 1.5]));
+
+        for &x in [-1.3, 0., 0.7, 2.].iter() {
+            let pd = p.ddpoly(x, 7);
+            let mut dp = p.clone();
+            for &pd_k in pd.iter() {
+                assert!((pd_k - dp.eval(x)).abs() < 1e-12 * pd_k.abs().max(1.));
+                dp = dp.derivative();
+            }
+        }
+    }
+
+    #[test]
+    fn poldiv_reconstructs_dividend() {
+        let p = Polynomial::new(arr1(&[3., -1., 0., 2., 5., -1.]));
+        let v = Polynomial::new(arr1(&[1., 2., -0.5]));
+
+        let (q, r) = p.poldiv(&v);
+        assert!(r.degree() < v.degree());
+
+        let back = &(&q * &v) + &r;
+        assert!(max_abs(&(back.coefficients() - p.coefficients())) < 1e-12);
+    }
+
+    #[test]
+    fn integral_of_known_polynomial() {
+        let p = Polynomial::new(arr1(&[1., 2., 3.]));
+
+        assert_eq!(p.integral().coefficients(), arr1(&[0., 1., 1., 1.]));
+        assert!((p.integrate(0., 2.) - 14.).abs() < 1e-12);
+        assert!((p.integrate(2., -1.) + 15.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn roots_of_real_and_complex_factors() {
+        // (x - 1)(x - 2)(x - 3)
+        let p = Polynomial::new(arr1(&[-6., 11., -6., 1.]));
+        let roots = p.roots().unwrap();
+        for (root, expected) in roots.iter().zip([1., 2., 3.]) {
+            assert!((root - expected).norm() < 1e-12);
+            assert_eq!(root.im, 0.);
+        }
+
+        // x^2 + 1
+        let roots = Polynomial::new(arr1(&[1., 0., 1.])).roots().unwrap();
+        assert_eq!(roots.len(), 2);
+        for root in roots.iter() {
+            assert!(root.re.abs() < 1e-12);
+            assert!((root.im.abs() - 1.).abs() < 1e-12);
+        }
+        assert!((roots[0].im + roots[1].im).abs() < 1e-12);
+    }
 }
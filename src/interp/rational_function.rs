@@ -1,78 +1,183 @@
-/// Come back to this ->
-use crate::interp::interpolator::Interpolate;
+use crate::interp::interpolator::Interpolate1D;
 use crate::table::bisect_hunt::BisectHunt1D;
 use crate::table::search::Search;
 use ndarray::prelude::*;
 use plotters::prelude::*;
+use std::fmt;
 
-/// 1 Dimensional polynomial interpolator
-/// Based on Neville's Algorithm
-pub struct Poly1D {
+/// Error returned when the interpolating rational function has a pole at `x`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pole {
+    pub x: f64, // The evaluation point
+}
+
+impl fmt::Display for Pole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "interpolating rational function has a pole at x = {}",
+            self.x
+        )
+    }
+}
+
+impl std::error::Error for Pole {}
+
+/// Error returned when the number of points per interpolation is out of range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidPointCount {
+    pub m: usize, // The requested number of points
+    pub n: usize, // The number of points in the table
+}
+
+impl fmt::Display for InvalidPointCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} points per interpolation requested from a table of {}. Must be between 2 and the table length",
+            self.m, self.n
+        )
+    }
+}
+
+impl std::error::Error for InvalidPointCount {}
+
+/// 1 Dimensional diagonal rational function interpolator
+/// Based on the Bulirsch-Stoer algorithm
+pub struct Rational1D {
     x: Array1<f64>,       // x table
     y: Array1<f64>,       // y table
-    m: usize,             // Polynomial degree / bracket size
+    m: usize,             // Number of points in the bracket
     search: BisectHunt1D, // Search Algorithm
 }
 
-impl Poly1D {
+impl Rational1D {
     /// Constructor
     /// #  Arguments
     /// * `x` - A monotonicly ascending or decending table of `x\[0\], ..., x\[n-1\]`
     /// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
-    /// * `m` - Polynomial deree. Must be lesser than the length of `x`
+    /// * `m` - Number of points used in each interpolation, not a degree. `2 <= m <= x.len()`.
+    ///   Odd `m` gives numerator and denominator of equal degree `(m - 1) / 2`
     /// # Returns
-    /// * `Self` - Poly1D Instaciate
-    pub fn new(x: Array1<f64>, y: Array1<f64>, m: usize) -> Self {
+    /// * `Result<Self, InvalidPointCount>` - Rational1D Instaciate, or `Err` if `m` is out of range
+    pub fn new(x: Array1<f64>, y: Array1<f64>, m: usize) -> Result<Self, InvalidPointCount> {
+        if m < 2 || m > x.len() {
+            return Err(InvalidPointCount { m, n: x.len() });
+        }
+
         // Instaciate search algorithm
         let search = BisectHunt1D::new(&x, m);
-        Self {
-            x: x,
-            y: y,
-            m: m,
-            search: search,
-        }
+        Ok(Self { x, y, m, search })
     }
 
     /// Raw Interpolator
     /// #  Arguments
     /// * `x` - The x value for which `f(x)` is being approximated
-    /// * `i` - The index for which `x\[i\] <= x <= x\[i + 1\]` is guarenteed
+    /// * `i` - The first index of the bracket `x\[i\], ..., x\[i + m - 1\]`
     /// # Returns
-    /// * `y : f64` - An approximation of `f(x)`
-    fn _interpolate(&mut self, x: f64, i: usize) -> f64 {
-        let xa = self.x.slice(s![i..i + self.m + 1]);
-        let mut ya = self.y.slice_mut(s![i..i + self.m + 1]).to_owned();
-
-        for m in 1..self.m + 1 {
-            for n in 0..self.m - m {
-                let p_ip1_j = ya[n + 1];
-                let p_i_jm1 = ya[n];
-                let x_i = xa[n];
-                let x_j = xa[n + m];
-                ya[n] = (((x - x_i) * p_ip1_j) - ((x - x_j) * p_i_jm1)) / (x_j - x_i);
+    /// * `Result<(f64, f64), Pole>` - An approximation of `f(x)` and an estimate of its error
+    pub fn raw_interpolate(&self, x: f64, i: usize) -> Result<(f64, f64), Pole> {
+        // Prevents a rare zero-over-zero condition
+        const TINY: f64 = 1.0e-99;
+
+        let mm = self.m;
+        let xa = self.x.slice(s![i..i + mm]);
+        let ya = self.y.slice(s![i..i + mm]);
+
+        let mut c = ya.to_owned();
+        let mut d = ya.mapv(|v| v + TINY);
+
+        // Find the closest table entry, returning exact hits directly
+        let mut ns = 0;
+        let mut hh = (x - xa[0]).abs();
+        for (j, &x_j) in xa.iter().enumerate() {
+            let h = (x - x_j).abs();
+            if h == 0.0 {
+                return Ok((ya[j], 0.0));
+            } else if h < hh {
+                ns = j;
+                hh = h;
             }
         }
 
-        return ya[0];
+        // Initial approximation, then walk the tableau towards the closest entry
+        let mut y = ya[ns];
+        let mut ns = ns as isize - 1;
+        let mut dy = 0.0;
+
+        for m in 1..mm {
+            for n in 0..mm - m {
+                let w = c[n + 1] - d[n];
+                let h = xa[n + m] - x;
+                let t = (xa[n] - x) * d[n] / h;
+                let dd = t - c[n + 1];
+
+                // Pole at the requested value of x
+                if dd == 0.0 {
+                    return Err(Pole { x });
+                }
+
+                let dd = w / dd;
+                d[n] = c[n + 1] * dd;
+                c[n] = t * dd;
+            }
+
+            // Take the straightest path through the tableau
+            dy = if 2 * (ns + 1) < (mm - m) as isize {
+                c[(ns + 1) as usize]
+            } else {
+                ns -= 1;
+                d[(ns + 1) as usize]
+            };
+            y += dy;
+        }
+
+        Ok((y, dy))
+    }
+
+    /// Interpolation, reporting a pole as an error
+    /// #  Arguments
+    /// * `x` - The x value for which `f(x)` is being approximated
+    /// # Returns
+    /// * `Result<(f64, f64), Pole>` - An approximation of `f(x)` and an estimate of its error
+    pub fn try_interpolate(&mut self, x: f64) -> Result<(f64, f64), Pole> {
+        // Get indecies
+        let i = self.search.locate(&self.x, x);
+
+        // Evaluate
+        self.raw_interpolate(x, i)
     }
 }
 
-impl Interpolate1D for Poly1D {
+impl Interpolate1D for Rational1D {
     type Dtype = f64;
     type Data = Array1<Self::Dtype>;
     type Index = usize;
 
     /// Interpolation
+    /// Panics if the interpolating function has a pole at `x`. See `try_interpolate`
     /// #  Arguments
     /// * `x` - The x value for which `f(x)` is being approximated
     /// # Returns
     /// * `y : f64` - An approximation of `f(x)`
     fn interpolate(&mut self, x: Self::Dtype) -> Self::Dtype {
-        // Get indecies
-        let i = self.search.locate(&self.x, x);
+        match self.try_interpolate(x) {
+            Ok((y, _)) => y,
+            Err(pole) => panic!("{pole}"),
+        }
+    }
 
-        // Evaluate
-        self._interpolate(x, i)
+    /// Interpolation with error estimate
+    /// Panics if the interpolating function has a pole at `x`. See `try_interpolate`
+    /// #  Arguments
+    /// * `x` - The x value for which `f(x)` is being approximated
+    /// # Returns
+    /// * `(y, dy) : (f64, f64)` - An approximation of `f(x)` and an estimate of its error
+    fn interpolate_with_error(&mut self, x: Self::Dtype) -> (Self::Dtype, Self::Dtype) {
+        match self.try_interpolate(x) {
+            Ok(y_dy) => y_dy,
+            Err(pole) => panic!("{pole}"),
+        }
     }
 }
 
@@ -80,25 +185,30 @@ pub fn proof() {
     // Revisit this
     println!("Starting proof...");
 
-    let x_gt = Array1::linspace(0., 3.14159265 * 2., 1000);
-    let y_gt = x_gt.clone().map(|&x| f64::sin(x));
+    // Poles at x = +/- 0.1i, just off the real axis
+    fn f(x: f64) -> f64 {
+        1.0 / (x * x + 0.01)
+    }
 
-    // let iter_gt = std::iter::zip(x_gt, y_gt);
+    let x_gt = Array1::linspace(-1., 1., 1000);
+    let y_gt = x_gt.mapv(f);
 
-    let x = Array1::linspace(0., 3.14159265 * 2., 7);
+    let x_tab = Array1::linspace(-1., 1., 21);
+    let y_tab = x_tab.mapv(f);
 
-    let mut my_interp = Poly1D::new(x_gt.clone(), y_gt.clone(), 4);
+    let mut my_interp = Rational1D::new(x_tab, y_tab, 4).unwrap();
 
-    let y = x.clone().map(|&x| my_interp.interpolate(x));
+    let y = x_gt.mapv(|x| my_interp.interpolate(x));
 
-    let root_area = BitMapBackend::new("images/Poly1D_proof.png", (1920, 1040)).into_drawing_area();
+    let root_area =
+        BitMapBackend::new("images/Rational1D_proof.png", (1920, 1040)).into_drawing_area();
     root_area.fill(&WHITE).unwrap();
 
     let mut ctx = ChartBuilder::on(&root_area)
         .set_label_area_size(LabelAreaPosition::Left, 40)
         .set_label_area_size(LabelAreaPosition::Bottom, 40)
-        .caption("Linear 1D Interpolation Proof", ("sans-serif", 40))
-        .build_cartesian_2d(0f64..(3.14159265f64 * 2.), -1.0f64..1.0f64)
+        .caption("Rational 1D Interpolation Proof", ("sans-serif", 40))
+        .build_cartesian_2d(-1.0f64..1.0f64, 0.0f64..110.0f64)
         .unwrap();
 
     ctx.configure_mesh().draw().unwrap();
@@ -110,10 +220,64 @@ pub fn proof() {
     .unwrap();
 
     ctx.draw_series(LineSeries::new(
-        x.iter().zip(y.iter()).map(|(&x, &y)| (x, y)),
+        x_gt.iter().zip(y.iter()).map(|(&x, &y)| (x, y)),
         &GREEN,
     ))
     .unwrap();
 
     println!("Proof complete.");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_count_must_fit_the_table() {
+        let x = arr1(&[0.0, 1.0, 2.0, 3.0]);
+        let y = x.mapv(|x| x * x);
+
+        assert!(Rational1D::new(x.clone(), y.clone(), 4).is_ok());
+        assert_eq!(
+            Rational1D::new(x.clone(), y.clone(), 5).err(),
+            Some(InvalidPointCount { m: 5, n: 4 })
+        );
+        assert_eq!(
+            Rational1D::new(x, y, 1).err(),
+            Some(InvalidPointCount { m: 1, n: 4 })
+        );
+    }
+
+    #[test]
+    fn reproduces_rational_function() {
+        fn f(x: f64) -> f64 {
+            (2.0 + x) / (1.0 - 0.5 * x)
+        }
+
+        let x = arr1(&[-1.0, -0.4, 0.3, 0.8, 1.2]);
+        let y = x.mapv(f);
+        let mut rational = Rational1D::new(x, y, 3).unwrap();
+
+        for &x_q in Array1::linspace(-1., 1.2, 45).iter() {
+            assert!((rational.interpolate(x_q) - f(x_q)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn pole_is_reported() {
+        let x = arr1(&[-2.0, -1.0, 1.0, 2.0]);
+        let y = x.mapv(|x| 1.0 / x);
+        let mut rational = Rational1D::new(x, y, 4).unwrap();
+
+        assert_eq!(rational.try_interpolate(0.0), Err(Pole { x: 0.0 }));
+        assert!(rational.try_interpolate(0.5).is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn interpolate_panics_at_pole() {
+        let x = arr1(&[-1.0, 1.0, 2.0]);
+        let y = x.mapv(|x| 1.0 / x);
+        Rational1D::new(x, y, 3).unwrap().interpolate(0.0);
+    }
+}
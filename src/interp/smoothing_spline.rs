@@ -0,0 +1,326 @@
+use crate::interp::interpolator::Interpolate1D;
+use crate::interp::spline::{BoundaryCondition, Spline1D};
+use crate::linalg::bandec::Bandec;
+use ndarray::prelude::*;
+use ndarray::Zip;
+
+/// Smoothing parameter of a smoothing spline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Lambda {
+    Fixed(f64), // Given. 0 interpolates, large values tend to a straight line fit
+    Gcv,        // Chosen by minimising the generalised cross-validation score
+}
+
+/// 1 Dimensional Cubic Smoothing Spline
+/// Minimises `sum(((y\[i\] - g(x\[i\])) / sigma\[i\])^2) + lambda * integral(g''^2)`
+/// Based on the Reinsch algorithm. The result is the natural spline through the smoothed values
+pub struct SmoothingSpline1D {
+    spline: Spline1D, // Natural spline through the smoothed values
+    g: Array1<f64>,   // Smoothed values at the knots
+    lambda: f64,      // Smoothing parameter
+}
+
+/// Banded matrices of the Reinsch system
+struct Penalty {
+    qt: Array2<f64>, // Second divided difference operator Q^T, (n-2) x 3 compact
+    r: Array2<f64>,  // Curvature penalty R, (n-2) x 5 compact
+    m: Array2<f64>,  // Q^T diag(sigma^2) Q, (n-2) x 5 compact
+}
+
+impl SmoothingSpline1D {
+    /// Constructor
+    /// #  Arguments
+    /// * `x` - A monotonicly ascending or decending table of `x\[0\], ..., x\[n-1\]`
+    /// * `y` - A table of noisy measurements of `f(x\[0\]), ..., f(x\[n-1\])`
+    /// * `sigma` - A table of the standard deviations of `y\[0\], ..., y\[n-1\]`
+    /// * `lambda` - Smoothing parameter, or `Lambda::Gcv` to choose it from the data
+    /// # Returns
+    /// * `Self` - SmoothingSpline1D Instaciate
+    pub fn new(x: Array1<f64>, y: Array1<f64>, sigma: Array1<f64>, lambda: Lambda) -> Self {
+        let n = x.len();
+
+        assert!(n >= 3, "SmoothingSpline1D: at least 3 points are required");
+        assert!(
+            y.len() == n && sigma.len() == n,
+            "SmoothingSpline1D: `x`, `y` and `sigma` must have equal length"
+        );
+        assert!(
+            sigma.iter().all(|&s| s > 0.0),
+            "SmoothingSpline1D: `sigma` must be positive"
+        );
+
+        let penalty = Penalty::new(&x, &sigma);
+
+        let lambda = match lambda {
+            Lambda::Fixed(lambda) => {
+                assert!(
+                    lambda >= 0.0,
+                    "SmoothingSpline1D: `lambda` must not be negative"
+                );
+                lambda
+            }
+            Lambda::Gcv => penalty.gcv_lambda(&y, &sigma),
+        };
+
+        let g = penalty.fit(&y, &sigma, lambda);
+        let spline = Spline1D::new(
+            x,
+            g.clone(),
+            BoundaryCondition::Natural,
+            BoundaryCondition::Natural,
+        );
+
+        Self { spline, g, lambda }
+    }
+
+    /// Smoothing parameter used in the fit
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Smoothed values at the knots
+    pub fn fitted(&self) -> &Array1<f64> {
+        &self.g
+    }
+
+    /// First derivative
+    /// #  Arguments
+    /// * `x` - The x value for which `f'(x)` is being approximated
+    /// # Returns
+    /// * `dy : f64` - An approximation of `f'(x)`
+    pub fn derivative(&mut self, x: f64) -> f64 {
+        self.spline.derivative(x)
+    }
+
+    /// Second derivative
+    /// #  Arguments
+    /// * `x` - The x value for which `f''(x)` is being approximated
+    /// # Returns
+    /// * `d2y : f64` - An approximation of `f''(x)`
+    pub fn second_derivative(&mut self, x: f64) -> f64 {
+        self.spline.second_derivative(x)
+    }
+
+    /// Definite integral of the spline
+    /// #  Arguments
+    /// * `a` - Lower limit
+    /// * `b` - Upper limit
+    /// # Returns
+    /// * `integral : f64` - An approximation of the integral of `f` from `a` to `b`
+    pub fn integrate(&mut self, a: f64, b: f64) -> f64 {
+        self.spline.integrate(a, b)
+    }
+}
+
+impl Penalty {
+    fn new(x: &Array1<f64>, sigma: &Array1<f64>) -> Self {
+        let n = x.len();
+        let h = Array1::from_shape_fn(n - 1, |k| (x[k + 1] - x[k]).abs());
+
+        // Row j acts on y[j], y[j + 1], y[j + 2]
+        let qt = Array2::from_shape_fn([n - 2, 3], |(j, c)| match c {
+            0 => 1.0 / h[j],
+            1 => -1.0 / h[j] - 1.0 / h[j + 1],
+            _ => 1.0 / h[j + 1],
+        });
+
+        let mut r = Array2::<f64>::zeros([n - 2, 5]);
+        let mut m = Array2::<f64>::zeros([n - 2, 5]);
+
+        for j in 0..n - 2 {
+            r[[j, 2]] = (h[j] + h[j + 1]) / 3.0;
+            if j > 0 {
+                r[[j, 1]] = h[j] / 6.0;
+            }
+            if j + 1 < n - 2 {
+                r[[j, 3]] = h[j + 1] / 6.0;
+            }
+
+            // Rows j and l of Q^T overlap on columns max(j, l), ..., min(j, l) + 2
+            for l in j.saturating_sub(2)..(j + 3).min(n - 2) {
+                m[[j, 2 + l - j]] = (j.max(l)..=j.min(l) + 2)
+                    .map(|k| qt[[j, k - j]] * sigma[k] * sigma[k] * qt[[l, k - l]])
+                    .sum();
+            }
+        }
+
+        Self { qt, r, m }
+    }
+
+    /// Factorises `R + lambda Q^T diag(sigma^2) Q`
+    fn factor(&self, lambda: f64) -> Bandec {
+        Bandec::new(&(&self.r + &(lambda * &self.m)), 2, 2)
+            .expect("SmoothingSpline1D: the Reinsch system is positive definite")
+    }
+
+    /// Smoothed values `g = y - lambda diag(sigma^2) Q gamma`
+    fn smooth(&self, a: &Bandec, y: &Array1<f64>, sigma: &Array1<f64>, lambda: f64) -> Array1<f64> {
+        let n = y.len();
+
+        // Second derivatives at the interior knots
+        let qty =
+            Array1::from_shape_fn(n - 2, |j| (0..3).map(|c| self.qt[[j, c]] * y[j + c]).sum());
+        let gamma = a.solve(&qty);
+
+        Array1::from_shape_fn(n, |k| {
+            let q_gamma: f64 = (k.saturating_sub(2)..=k.min(n - 3))
+                .map(|j| self.qt[[j, k - j]] * gamma[j])
+                .sum();
+            y[k] - lambda * sigma[k] * sigma[k] * q_gamma
+        })
+    }
+
+    fn fit(&self, y: &Array1<f64>, sigma: &Array1<f64>, lambda: f64) -> Array1<f64> {
+        self.smooth(&self.factor(lambda), y, sigma, lambda)
+    }
+
+    /// Generalised cross-validation score
+    fn gcv(&self, y: &Array1<f64>, sigma: &Array1<f64>, lambda: f64) -> f64 {
+        let n = y.len();
+        let a = self.factor(lambda);
+        let g = self.smooth(&a, y, sigma, lambda);
+
+        // Trace of the influence matrix, 2 + tr(A^-1 R)
+        let mut trace = 2.0;
+        for l in 0..n - 2 {
+            let mut e = Array1::<f64>::zeros([n - 2]);
+            for i in l.saturating_sub(1)..(l + 2).min(n - 2) {
+                e[i] = self.r[[i, 2 + l - i]];
+            }
+            trace += a.solve(&e)[l];
+        }
+
+        let rss: f64 = Zip::from(y)
+            .and(&g)
+            .and(sigma)
+            .fold(0.0, |acc, &y, &g, &s| acc + ((y - g) / s).powi(2));
+
+        n as f64 * rss / (n as f64 - trace).powi(2)
+    }
+
+    /// Lambda minimising the GCV score. Grid search in log lambda, refined by golden section
+    fn gcv_lambda(&self, y: &Array1<f64>, sigma: &Array1<f64>) -> f64 {
+        const R: f64 = 0.618_033_988_749_895;
+
+        // Natural scale at which the penalty and residual terms balance
+        let scale = self.r.column(2).sum() / self.m.column(2).sum();
+        let score = |t: f64| self.gcv(y, sigma, scale * 10f64.powf(t));
+
+        let grid = Array1::linspace(-6.0, 6.0, 25);
+        let best = grid
+            .iter()
+            .map(|&t| (t, score(t)))
+            .fold(
+                (0.0, f64::INFINITY),
+                |acc, s| if s.1 < acc.1 { s } else { acc },
+            )
+            .0;
+
+        let (mut a, mut b) = (best - 0.5, best + 0.5);
+        let mut c = b - R * (b - a);
+        let mut d = a + R * (b - a);
+        let (mut fc, mut fd) = (score(c), score(d));
+
+        while b - a > 1.0e-3 {
+            if fc < fd {
+                b = d;
+                d = c;
+                fd = fc;
+                c = b - R * (b - a);
+                fc = score(c);
+            } else {
+                a = c;
+                c = d;
+                fc = fd;
+                d = a + R * (b - a);
+                fd = score(d);
+            }
+        }
+
+        scale * 10f64.powf(0.5 * (a + b))
+    }
+}
+
+impl Interpolate1D for SmoothingSpline1D {
+    type Dtype = f64;
+    type Data = Array1<Self::Dtype>;
+    type Index = usize;
+
+    /// Interpolation
+    /// #  Arguments
+    /// * `x` - The x value for which `f(x)` is being approximated
+    /// # Returns
+    /// * `y : f64` - An approximation of `f(x)`
+    fn interpolate(&mut self, x: Self::Dtype) -> Self::Dtype {
+        self.spline.interpolate(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Noisy samples of a smooth curve on uneven knots
+    fn data() -> (Array1<f64>, Array1<f64>, Array1<f64>) {
+        let x = arr1(&[0.0, 0.3, 0.7, 1.2, 1.5, 2.1, 2.4, 3.0, 3.6, 4.1]);
+        let noise = arr1(&[
+            0.05, -0.08, 0.02, 0.1, -0.04, -0.07, 0.06, -0.02, 0.09, -0.05,
+        ]);
+        let y = x.mapv(f64::sin) + noise;
+        let sigma = Array1::from_elem(x.len(), 0.1);
+        (x, y, sigma)
+    }
+
+    // Evenly spaced samples with noise at the level of `sigma`, dense enough
+    // that the GCV score has a clear interior minimum
+    fn dense_data() -> (Array1<f64>, Array1<f64>, Array1<f64>) {
+        let x = Array1::linspace(0., 4., 41);
+        let noise = Array1::from_shape_fn(41, |k| 0.02 * ((7 * k * k + 3 * k) % 11) as f64 - 0.1);
+        let y = x.mapv(f64::sin) + noise;
+        let sigma = Array1::from_elem(x.len(), 0.1);
+        (x, y, sigma)
+    }
+
+    #[test]
+    fn zero_lambda_interpolates() {
+        let (x, y, sigma) = data();
+        let spline = SmoothingSpline1D::new(x, y.clone(), sigma, Lambda::Fixed(0.0));
+
+        for (&g, &y) in spline.fitted().iter().zip(y.iter()) {
+            assert!((g - y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn huge_lambda_fits_a_straight_line() {
+        let (x, y, sigma) = data();
+        let spline = SmoothingSpline1D::new(x.clone(), y, sigma, Lambda::Fixed(1e12));
+        let g = spline.fitted();
+
+        // Second divided differences of the fitted values vanish
+        for i in 1..x.len() - 1 {
+            let left = (g[i] - g[i - 1]) / (x[i] - x[i - 1]);
+            let right = (g[i + 1] - g[i]) / (x[i + 1] - x[i]);
+            assert!((right - left).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn gcv_lambda_minimises_the_score() {
+        let (x, y, sigma) = dense_data();
+        let spline = SmoothingSpline1D::new(x.clone(), y.clone(), sigma.clone(), Lambda::Gcv);
+        let lambda = spline.lambda();
+        assert!(lambda.is_finite() && lambda > 0.0);
+
+        let penalty = Penalty::new(&x, &sigma);
+        let score = |lambda: f64| penalty.gcv(&y, &sigma, lambda);
+        let best = score(lambda);
+
+        // No worse than a decade either side, nor than a brute-force scan over twelve decades
+        assert!(best <= score(lambda / 10.0));
+        assert!(best <= score(lambda * 10.0));
+        for &t in Array1::linspace(-6., 6., 241).iter() {
+            assert!(best <= score(lambda * 10f64.powf(t)) * (1.0 + 1e-6));
+        }
+    }
+}
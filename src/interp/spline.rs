@@ -1,14 +1,26 @@
 use crate::interp::interpolator::Interpolate1D;
+use crate::linalg::tridag::{cyclic, tridag};
 use crate::table::bisect_hunt::BisectHunt1D;
 use crate::table::search::Search;
 use ndarray::prelude::*;
 use plotters::prelude::*;
 
+/// End condition for a cubic spline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoundaryCondition {
+    Natural,               // y'' = 0
+    Clamped(f64),          // y' given
+    SecondDerivative(f64), // y'' given
+    NotAKnot,              // y''' continuous across the knot next to the end
+    Periodic,              // y, y' and y'' match at both ends. Must be applied to both ends
+}
+
 /// 1 Dimensional Cubic Spine Interpolator
 pub struct Spline1D {
     x: Array1<f64>,       // x   table
     y: Array1<f64>,       // y   table
     y2: Array1<f64>,      // y'' table
+    yint: Array1<f64>,    // Integral from x[0] to each knot
     search: BisectHunt1D, // Search Algorithm
 }
 
@@ -17,66 +29,263 @@ impl Spline1D {
     /// #  Arguments
     /// * `x` - A monotonicly ascending or decending table of `x\[0\], ..., x\[n-1\]`
     /// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
-    /// FINISH DOCS
+    /// * `start` - Condition at `x\[0\]`
+    /// * `end` - Condition at `x\[n-1\]`
     /// # Returns
-    /// * `Self` - Linear1D Instaciate
-    pub fn new(x: Array1<f64>, y: Array1<f64>, yp1: Option<f64>, ypn: Option<f64>) -> Self {
+    /// * `Self` - Spline1D Instaciate
+    pub fn new(
+        x: Array1<f64>,
+        y: Array1<f64>,
+        start: BoundaryCondition,
+        end: BoundaryCondition,
+    ) -> Self {
+        assert_eq!(
+            x.len(),
+            y.len(),
+            "Spline1D: `x` and `y` must have equal length"
+        );
+        assert!(x.len() >= 2, "Spline1D: at least 2 points are required");
+
         let search = BisectHunt1D::new(&x, 2);
-        let y2 = Self::set_y2(&x, &y, &yp1, &ypn);
+        let y2 = Self::set_y2(&x, &y, start, end);
+
+        let mut spline = Self {
+            x,
+            y,
+            y2,
+            yint: Array1::zeros([0]),
+            search,
+        };
+        spline.yint = spline.set_yint();
 
-        Self {
-            x: x,
-            y: y,
-            y2: y2,
-            search: search,
-        }
+        spline
     }
 
+    /// Solves for the second derivatives at the knots
+    /// #  Arguments
+    /// * `x` - The x table
+    /// * `y` - The y table
+    /// * `start` - Condition at `x\[0\]`
+    /// * `end` - Condition at `x\[n-1\]`
+    /// # Returns
+    /// * `y2 : Array1<f64>` - The y'' table
     fn set_y2(
         x: &Array1<f64>,
         y: &Array1<f64>,
-        yp1: &Option<f64>,
-        ypn: &Option<f64>,
+        start: BoundaryCondition,
+        end: BoundaryCondition,
     ) -> Array1<f64> {
+        use BoundaryCondition::*;
+
         let n = y.len();
 
-        let mut y2 = Array1::<f64>::zeros([n]);
-        let mut u = Array1::<f64>::zeros([n - 1]);
+        if start == Periodic || end == Periodic {
+            assert!(
+                start == end,
+                "Spline1D: periodic boundaries must be applied at both ends"
+            );
+            return Self::set_y2_periodic(x, y);
+        }
 
-        if yp1.is_some() {
-            y2[0] = -0.5;
-            u[0] = (3.0 / (x[1] - x[0])) * ((y[1] - y[0]) / (x[1] - x[0]) - yp1.unwrap());
+        if start == NotAKnot || end == NotAKnot {
+            assert!(n >= 4, "Spline1D: not-a-knot requires at least 4 points");
         }
 
-        let a = &x.slice(s![0..n - 2]).to_owned(); //  i - 1
-        let b = &x.slice(s![1..n - 1]).to_owned(); //  i
-        let c = &x.slice(s![2..n]).to_owned(); //      i + 1
+        // Tridiagonal system for y''
+        let mut a = Array1::<f64>::zeros([n]); // Sub-diagonal
+        let mut b = Array1::<f64>::ones([n]); //  Diagonal
+        let mut c = Array1::<f64>::zeros([n]); // Super-diagonal
+        let mut r = Array1::<f64>::zeros([n]); // Right-hand side
+
+        // Continuity of y' at the interior knots
+        for i in 1..n - 1 {
+            a[i] = (x[i] - x[i - 1]) / 6.0;
+            b[i] = (x[i + 1] - x[i - 1]) / 3.0;
+            c[i] = (x[i + 1] - x[i]) / 6.0;
+            r[i] = (y[i + 1] - y[i]) / (x[i + 1] - x[i]) - (y[i] - y[i - 1]) / (x[i] - x[i - 1]);
+        }
 
-        let d = y2.slice(s![0..n - 2]).to_owned(); // i - 1
+        // Lower boundary
+        let h0 = x[1] - x[0];
+        match start {
+            Natural => {}
+            SecondDerivative(y2) => r[0] = y2,
+            Clamped(yp) => {
+                b[0] = h0 / 3.0;
+                c[0] = h0 / 6.0;
+                r[0] = (y[1] - y[0]) / h0 - yp;
+            }
+            NotAKnot => {
+                // y''' continuous at x[1]. Eliminate y''[0] from the first interior row
+                let h1 = x[2] - x[1];
+                a[1] = 0.0;
+                b[1] = (h0 + h1) * (h0 + 2.0 * h1) / (6.0 * h1);
+                c[1] = (h1 * h1 - h0 * h0) / (6.0 * h1);
+            }
+            Periodic => unreachable!(),
+        }
 
-        let sig = &((b - a) / (c - a));
-        let p = &((sig * d) + 2.);
+        // Upper boundary
+        let hn = x[n - 1] - x[n - 2];
+        match end {
+            Natural => {}
+            SecondDerivative(y2) => r[n - 1] = y2,
+            Clamped(yp) => {
+                a[n - 1] = hn / 6.0;
+                b[n - 1] = hn / 3.0;
+                r[n - 1] = yp - (y[n - 1] - y[n - 2]) / hn;
+            }
+            NotAKnot => {
+                // y''' continuous at x[n-2]. Eliminate y''[n-1] from the last interior row
+                let hm = x[n - 2] - x[n - 3];
+                a[n - 2] = (hm * hm - hn * hn) / (6.0 * hm);
+                b[n - 2] = (hm + hn) * (2.0 * hm + hn) / (6.0 * hm);
+                c[n - 2] = 0.0;
+            }
+            Periodic => unreachable!(),
+        }
 
-        y2.slice_mut(s![1..n - 1]).assign(&((sig - 1.) / p));
+        let mut y2 = tridag(&a, &b, &c, &r);
 
-        let mut un = 0.;
-        let mut qn = 0.;
+        // Recover the eliminated end values
+        if start == NotAKnot {
+            let h1 = x[2] - x[1];
+            y2[0] = ((h0 + h1) * y2[1] - h0 * y2[2]) / h1;
+        }
+        if end == NotAKnot {
+            let hm = x[n - 2] - x[n - 3];
+            y2[n - 1] = ((hm + hn) * y2[n - 2] - hn * y2[n - 3]) / hm;
+        }
 
-        if ypn.is_some() {
-            qn = 0.5;
-            un = (3.0 / (x[n - 1] - x[n - 2]))
-                * (ypn.unwrap() - (y[n - 1] - y[n - 2]) / (x[n - 1] - x[n - 2]));
+        y2
+    }
+
+    /// Solves for the second derivatives of a periodic spline
+    /// `y\[n-1\]` is taken to equal `y\[0\]`, so the system is cyclic in `y''\[0\], ..., y''\[n-2\]`
+    fn set_y2_periodic(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
+        let n = y.len();
+        let m = n - 1;
+
+        assert!(
+            n >= 4,
+            "Spline1D: periodic boundaries require at least 4 points"
+        );
+        assert!(
+            (y[0] - y[m]).abs() <= 1e-12 * y[0].abs().max(y[m].abs()).max(1.0),
+            "Spline1D: periodic boundaries require y[0] == y[n-1]"
+        );
+
+        // Interval widths and slopes, with interval m - 1 wrapping round before interval 0
+        let h = Array1::from_shape_fn(m, |i| x[i + 1] - x[i]);
+        let dy = Array1::from_shape_fn(m, |i| (y[i + 1] - y[i]) / h[i]);
+
+        let mut a = Array1::<f64>::zeros([m]); // Sub-diagonal
+        let mut b = Array1::<f64>::zeros([m]); // Diagonal
+        let mut c = Array1::<f64>::zeros([m]); // Super-diagonal
+        let mut r = Array1::<f64>::zeros([m]); // Right-hand side
+
+        // Continuity of y' at every knot, including x[0] = x[n-1]
+        for i in 0..m {
+            let im1 = (i + m - 1) % m;
+            a[i] = h[im1] / 6.0;
+            b[i] = (h[im1] + h[i]) / 3.0;
+            c[i] = h[i] / 6.0;
+            r[i] = dy[i] - dy[im1];
         }
 
-        y2[n - 1] = (un - qn * u[n - 2]) / (qn * y2[n - 2] + 1.);
+        let y2 = cyclic(&a, &b, &c, c[m - 1], a[0], &r);
 
-        let a = &y2.slice(s![0..n-2;-1]).to_owned(); //  k
-        let b = &y2.slice(s![1..n-1;-1]).to_owned(); // k + 1
-        let c = &u.slice(s![0..n-2;-1]).to_owned(); //   k
+        // y''[n-1] = y''[0]
+        Array1::from_shape_fn(n, |i| y2[i % m])
+    }
 
-        y2.slice_mut(s![..n-2;-1]).assign(&(a * b + c));
+    /// Cumulative integral of the spline from `x\[0\]` to each knot
+    fn set_yint(&self) -> Array1<f64> {
+        let n = self.x.len();
 
-        y2
+        let mut yint = Array1::<f64>::zeros([n]);
+        for i in 0..n - 1 {
+            yint[i + 1] = yint[i] + self.segment_integral(i, self.x[i + 1] - self.x[i]);
+        }
+
+        yint
+    }
+
+    /// Coefficients of the cubic on interval `i`
+    /// #  Arguments
+    /// * `i` - The interval `x\[i\], x\[i + 1\]`
+    /// # Returns
+    /// * `[a, b, c, d] : [f64; 4]` - `y = a + b t + c t^2 + d t^3` with `t = x - x\[i\]`
+    fn segment(&self, i: usize) -> [f64; 4] {
+        let h = self.x[i + 1] - self.x[i];
+
+        let a = self.y[i];
+        let b = (self.y[i + 1] - self.y[i]) / h - h * (2.0 * self.y2[i] + self.y2[i + 1]) / 6.0;
+        let c = self.y2[i] / 2.0;
+        let d = (self.y2[i + 1] - self.y2[i]) / (6.0 * h);
+
+        [a, b, c, d]
+    }
+
+    /// Integral of the cubic on interval `i` from `x\[i\]` to `x\[i\] + t`
+    fn segment_integral(&self, i: usize, t: f64) -> f64 {
+        let [a, b, c, d] = self.segment(i);
+        t * (a + t * (b / 2.0 + t * (c / 3.0 + t * d / 4.0)))
+    }
+
+    /// First derivative
+    /// #  Arguments
+    /// * `x` - The x value for which `f'(x)` is being approximated
+    /// # Returns
+    /// * `dy : f64` - An approximation of `f'(x)`
+    pub fn derivative(&mut self, x: f64) -> f64 {
+        // Get indecies
+        let i = self.search.locate(&self.x, x);
+
+        // Evaluate
+        let [_, b, c, d] = self.segment(i);
+        let t = x - self.x[i];
+        b + t * (2.0 * c + t * 3.0 * d)
+    }
+
+    /// Second derivative
+    /// #  Arguments
+    /// * `x` - The x value for which `f''(x)` is being approximated
+    /// # Returns
+    /// * `d2y : f64` - An approximation of `f''(x)`
+    pub fn second_derivative(&mut self, x: f64) -> f64 {
+        // Get indecies
+        let i = self.search.locate(&self.x, x);
+
+        // Evaluate
+        let [_, _, c, d] = self.segment(i);
+        let t = x - self.x[i];
+        2.0 * c + 6.0 * d * t
+    }
+
+    /// Definite integral of the spline
+    /// #  Arguments
+    /// * `a` - Lower limit
+    /// * `b` - Upper limit
+    /// # Returns
+    /// * `integral : f64` - An approximation of the integral of `f` from `a` to `b`
+    pub fn integrate(&mut self, a: f64, b: f64) -> f64 {
+        self.antiderivative(b) - self.antiderivative(a)
+    }
+
+    /// Integral of the spline from `x\[0\]` to `x`
+    fn antiderivative(&mut self, x: f64) -> f64 {
+        let i = self.search.locate(&self.x, x);
+        self.yint[i] + self.segment_integral(i, x - self.x[i])
+    }
+
+    /// The spline as piecewise cubics
+    /// # Returns
+    /// * `coef : Array2<f64>` - Row `i` holds `a, b, c, d` such that
+    ///   `y = a + b t + c t^2 + d t^3` with `t = x - x\[i\]`, on the interval `x\[i\], x\[i + 1\]`
+    pub fn piecewise_coefficients(&self) -> Array2<f64> {
+        let n = self.x.len();
+        Array2::from_shape_fn([n - 1, 4], |(i, k)| self.segment(i)[k])
     }
 
     fn _interpolate(&mut self, x: f64, i: usize) -> f64 {
@@ -95,9 +304,7 @@ impl Spline1D {
         let r = (a.powi(3) - a) * self.y2[i];
         let s = (b.powi(3) - b) * self.y2[i + 1];
 
-        let y = p + q + (r + s) * (h * h) / 6.0;
-
-        y
+        p + q + (r + s) * (h * h) / 6.0
     }
 }
 
@@ -123,14 +330,19 @@ pub fn proof() {
     // Revisit this
     println!("Starting proof...");
 
-    let x_gt = Array1::linspace(0., 3.14159265 * 2., 1000);
+    let x_gt = Array1::linspace(0., std::f64::consts::PI * 2., 1000);
     let y_gt = x_gt.clone().map(|&x| f64::sin(x));
 
     // let iter_gt = std::iter::zip(x_gt, y_gt);
 
-    let x = Array1::linspace(0., 3.14159265 * 2., 7);
+    let x = Array1::linspace(0., std::f64::consts::PI * 2., 7);
 
-    let mut my_interp = Spline1D::new(x_gt.clone(), y_gt.clone(), None, None);
+    let mut my_interp = Spline1D::new(
+        x_gt.clone(),
+        y_gt.clone(),
+        BoundaryCondition::Natural,
+        BoundaryCondition::Natural,
+    );
 
     let y = x.clone().map(|&x| my_interp.interpolate(x));
 
@@ -142,7 +354,7 @@ pub fn proof() {
         .set_label_area_size(LabelAreaPosition::Left, 40)
         .set_label_area_size(LabelAreaPosition::Bottom, 40)
         .caption("Linear 1D Interpolation Proof", ("sans-serif", 40))
-        .build_cartesian_2d(0f64..(3.14159265f64 * 2.), -1.0f64..1.0f64)
+        .build_cartesian_2d(0f64..(std::f64::consts::PI * 2.), -1.0f64..1.0f64)
         .unwrap();
 
     ctx.configure_mesh().draw().unwrap();
@@ -161,3 +373,163 @@ pub fn proof() {
 
     println!("Proof complete.");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BoundaryCondition::*;
+    use super::*;
+    use crate::test_utils::knots;
+
+    fn cubic(x: f64) -> f64 {
+        2.0 * x * x * x - x * x + 0.5 * x - 3.0
+    }
+
+    fn cubic_prime(x: f64) -> f64 {
+        6.0 * x * x - 2.0 * x + 0.5
+    }
+
+    #[test]
+    fn natural_spline_reproduces_linear_data() {
+        let x = knots();
+        let y = x.mapv(|x| 1.5 - 2.0 * x);
+        let mut spline = Spline1D::new(x, y, Natural, Natural);
+
+        for &x_q in Array1::linspace(-1., 2., 61).iter() {
+            assert!((spline.interpolate(x_q) - (1.5 - 2.0 * x_q)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn clamped_spline_reproduces_cubic_data() {
+        let x = knots();
+        let y = x.mapv(cubic);
+        let (yp1, ypn) = (cubic_prime(x[0]), cubic_prime(x[x.len() - 1]));
+        let mut spline = Spline1D::new(x, y, Clamped(yp1), Clamped(ypn));
+
+        for &x_q in Array1::linspace(-1., 2., 61).iter() {
+            assert!((spline.interpolate(x_q) - cubic(x_q)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn clamped_spline_reproduces_cubic_data_on_descending_tables() {
+        let x = knots().slice(s![..;-1]).to_owned();
+        let y = x.mapv(cubic);
+        let (yp1, ypn) = (cubic_prime(x[0]), cubic_prime(x[x.len() - 1]));
+        let mut spline = Spline1D::new(x, y, Clamped(yp1), Clamped(ypn));
+
+        for &x_q in Array1::linspace(2., -1., 61).iter() {
+            assert!((spline.interpolate(x_q) - cubic(x_q)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn clamped_spline_derivatives_and_integral_match_cubic() {
+        let x = knots();
+        let y = x.mapv(cubic);
+        let (yp1, ypn) = (cubic_prime(x[0]), cubic_prime(x[x.len() - 1]));
+        let mut spline = Spline1D::new(x, y, Clamped(yp1), Clamped(ypn));
+
+        for &x_q in Array1::linspace(-1., 2., 61).iter() {
+            assert!((spline.derivative(x_q) - cubic_prime(x_q)).abs() < 1e-9);
+            assert!((spline.second_derivative(x_q) - (12.0 * x_q - 2.0)).abs() < 1e-8);
+        }
+
+        // Antiderivative of the cubic
+        let big_f = |x: f64| 0.5 * x.powi(4) - x.powi(3) / 3.0 + 0.25 * x * x - 3.0 * x;
+        for (a, b) in [(-1.0, 2.0), (-0.5, 0.22), (1.7, 0.1), (0.3, 0.3)] {
+            assert!((spline.integrate(a, b) - (big_f(b) - big_f(a))).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn piecewise_coefficients_reproduce_spline() {
+        let x = knots();
+        let y = x.mapv(|x| x.sin());
+        let mut spline = Spline1D::new(x.clone(), y, Natural, Clamped(0.3));
+        let coef = spline.piecewise_coefficients();
+
+        for i in 0..x.len() - 1 {
+            let x_q = 0.3 * x[i] + 0.7 * x[i + 1];
+            let t = x_q - x[i];
+            let y_q = coef[[i, 0]] + t * (coef[[i, 1]] + t * (coef[[i, 2]] + t * coef[[i, 3]]));
+            assert!((spline.interpolate(x_q) - y_q).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn second_derivative_ends_are_honoured() {
+        let x = knots();
+        let y = x.mapv(cubic);
+        let (y2_1, y2_n) = (12.0 * x[0] - 2.0, 12.0 * x[x.len() - 1] - 2.0);
+        let mut spline = Spline1D::new(x, y, SecondDerivative(y2_1), SecondDerivative(y2_n));
+
+        for &x_q in Array1::linspace(-1., 2., 61).iter() {
+            assert!((spline.interpolate(x_q) - cubic(x_q)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn not_a_knot_spline_reproduces_cubic_data() {
+        let x = knots();
+        let y = x.mapv(cubic);
+        let mut spline = Spline1D::new(x, y, NotAKnot, NotAKnot);
+
+        for &x_q in Array1::linspace(-1., 2., 61).iter() {
+            assert!((spline.interpolate(x_q) - cubic(x_q)).abs() < 1e-10);
+        }
+
+        // Also on a uniform grid, where the eliminated rows are most delicate
+        let x = Array1::linspace(0., 1., 6);
+        let y = x.mapv(cubic);
+        let mut spline = Spline1D::new(x, y, NotAKnot, Clamped(cubic_prime(1.0)));
+
+        for &x_q in Array1::linspace(0., 1., 21).iter() {
+            assert!((spline.interpolate(x_q) - cubic(x_q)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn periodic_spline_matches_derivatives_at_ends() {
+        let x = arr1(&[0.0, 0.8, 1.9, 2.5, 3.7, 4.4, 5.6, std::f64::consts::TAU]);
+        let y = x.mapv(|x| x.sin() + 0.5 * (2.0 * x).cos());
+        let mut spline = Spline1D::new(x.clone(), y.clone(), Periodic, Periodic);
+
+        let tau = std::f64::consts::TAU;
+        assert!((spline.derivative(0.0) - spline.derivative(tau)).abs() < 1e-12);
+        assert!((spline.second_derivative(0.0) - spline.second_derivative(tau)).abs() < 1e-12);
+        for (&x_k, &y_k) in x.iter().zip(y.iter()) {
+            assert!((spline.interpolate(x_k) - y_k).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn periodic_spline_approximates_periodic_function() {
+        let f = |x: f64| x.sin() + 0.5 * (2.0 * x).cos();
+        let x = Array1::linspace(0., std::f64::consts::TAU, 65);
+        let y = x.mapv(f);
+        let mut spline = Spline1D::new(x, y, Periodic, Periodic);
+
+        for &x_q in Array1::linspace(0., std::f64::consts::TAU, 501).iter() {
+            assert!((spline.interpolate(x_q) - f(x_q)).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "periodic boundaries require y[0] == y[n-1]")]
+    fn periodic_spline_rejects_mismatched_ends() {
+        let x = arr1(&[0.0, 1.0, 2.0, 3.0, 4.0]);
+        let y = arr1(&[0.0, 1.0, 0.0, -1.0, 5.0]);
+        Spline1D::new(x, y, Periodic, Periodic);
+    }
+
+    #[test]
+    fn natural_spline_has_zero_end_curvature() {
+        let x = knots();
+        let y = x.mapv(cubic);
+        let spline = Spline1D::new(x, y, Natural, Natural);
+
+        assert_eq!(spline.y2[0], 0.0);
+        assert_eq!(spline.y2[spline.y2.len() - 1], 0.0);
+    }
+}
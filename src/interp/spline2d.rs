@@ -0,0 +1,118 @@
+use crate::interp::interpolator::{Interpolate1D, InterpolateND};
+use crate::interp::spline::{BoundaryCondition, Spline1D};
+use ndarray::prelude::*;
+
+/// 2 Dimensional Cubic Spline Interpolator on a rectangular grid
+/// Natural splines along each row of `y`, then a natural spline through the row results
+pub struct Spline2D {
+    x1: Array1<f64>,     // x1 table
+    rows: Vec<Spline1D>, // Spline along x2 for each x1[i]
+}
+
+impl Spline2D {
+    /// Constructor
+    /// #  Arguments
+    /// * `x1` - A monotonicly ascending or decending table of `x1\[0\], ..., x1\[m-1\]`
+    /// * `x2` - A monotonicly ascending or decending table of `x2\[0\], ..., x2\[n-1\]`
+    /// * `y` - An `m x n` table of `f(x1\[i\], x2\[j\])`
+    /// # Returns
+    /// * `Self` - Spline2D Instaciate
+    pub fn new(x1: Array1<f64>, x2: Array1<f64>, y: Array2<f64>) -> Self {
+        assert!(
+            x1.len() >= 2 && x2.len() >= 2,
+            "Spline2D: at least 2 points are required along each axis"
+        );
+        assert_eq!(
+            y.dim(),
+            (x1.len(), x2.len()),
+            "Spline2D: `y` must have shape (x1.len(), x2.len())"
+        );
+
+        let rows = y
+            .rows()
+            .into_iter()
+            .map(|row| {
+                Spline1D::new(
+                    x2.clone(),
+                    row.to_owned(),
+                    BoundaryCondition::Natural,
+                    BoundaryCondition::Natural,
+                )
+            })
+            .collect();
+
+        Self { x1, rows }
+    }
+}
+
+impl InterpolateND for Spline2D {
+    type YDim = Ix2;
+
+    type Dtype = f64;
+
+    type X<'a> = [f64; 2];
+    type IndX = [usize; 2];
+
+    type Y = f64;
+    type IndY = [usize; 2];
+
+    /// Interpolation
+    /// #  Arguments
+    /// * `x` - The point `\[x1, x2\]` for which `f(x1, x2)` is being approximated
+    /// # Returns
+    /// * `y : f64` - An approximation of `f(x1, x2)`
+    fn interpolate(&mut self, x: Self::X<'_>) -> Self::Y {
+        let [x1, x2] = x;
+
+        // Evaluate each row at x2
+        let column = self
+            .rows
+            .iter_mut()
+            .map(|row| row.interpolate(x2))
+            .collect::<Array1<f64>>();
+
+        // Spline through the row results, evaluated at x1
+        Spline1D::new(
+            self.x1.clone(),
+            column,
+            BoundaryCondition::Natural,
+            BoundaryCondition::Natural,
+        )
+        .interpolate(x1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::grid;
+
+    #[test]
+    fn reproduces_grid_values() {
+        let (x1, x2) = grid();
+        let y = Array2::from_shape_fn([x1.len(), x2.len()], |(i, j)| {
+            f64::sin(x1[i]) * f64::cos(x2[j])
+        });
+        let mut spline = Spline2D::new(x1.clone(), x2.clone(), y.clone());
+
+        for ((i, j), &y) in y.indexed_iter() {
+            assert!((spline.interpolate([x1[i], x2[j]]) - y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn approximates_smooth_function() {
+        let f = |x1: f64, x2: f64| f64::sin(x1) * f64::cos(x2);
+        let x1 = Array1::linspace(0., 3., 31);
+        let x2 = Array1::linspace(0., 2., 21);
+        let y = Array2::from_shape_fn([x1.len(), x2.len()], |(i, j)| f(x1[i], x2[j]));
+        let mut spline = Spline2D::new(x1, x2, y);
+
+        // Away from the natural ends, where the boundary error dominates
+        for &x1 in Array1::linspace(0.5, 2.5, 21).iter() {
+            for &x2 in Array1::linspace(0.5, 1.5, 11).iter() {
+                assert!((spline.interpolate([x1, x2]) - f(x1, x2)).abs() < 1e-5);
+            }
+        }
+    }
+}
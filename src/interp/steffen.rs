@@ -0,0 +1,146 @@
+use crate::interp::hermite::hermite_cubic;
+use crate::interp::interpolator::Interpolate1D;
+use crate::table::bisect_hunt::BisectHunt1D;
+use crate::table::search::Search;
+use ndarray::prelude::*;
+
+/// 1 Dimensional Steffen interpolator
+/// Monotone piecewise cubic Hermite. Extrema only occur at the knots
+pub struct Steffen1D {
+    x: Array1<f64>,       // x  table
+    y: Array1<f64>,       // y  table
+    d: Array1<f64>,       // y' table
+    search: BisectHunt1D, // Search Algorithm
+}
+
+impl Steffen1D {
+    /// Constructor
+    /// #  Arguments
+    /// * `x` - A monotonicly ascending or decending table of `x\[0\], ..., x\[n-1\]`
+    /// * `y` - A table of `f(x\[0\]), ..., f(x\[n-1\])`
+    /// # Returns
+    /// * `Self` - Steffen1D Instaciate
+    pub fn new(x: Array1<f64>, y: Array1<f64>) -> Self {
+        assert_eq!(
+            x.len(),
+            y.len(),
+            "Steffen1D: `x` and `y` must have equal length"
+        );
+        assert!(x.len() >= 2, "Steffen1D: at least 2 points are required");
+
+        let search = BisectHunt1D::new(&x, 2);
+        let d = Self::set_d(&x, &y);
+
+        Self { x, y, d, search }
+    }
+
+    /// Steffen's derivative estimates at the knots
+    fn set_d(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
+        let n = x.len();
+
+        let h = Array1::from_shape_fn(n - 1, |k| x[k + 1] - x[k]);
+        let delta = Array1::from_shape_fn(n - 1, |k| (y[k + 1] - y[k]) / h[k]);
+
+        if n == 2 {
+            return Array1::from_elem(n, delta[0]);
+        }
+
+        let mut d = Array1::<f64>::zeros([n]);
+
+        // Interior: parabola through three points, limited by the neighbouring slopes
+        for i in 1..n - 1 {
+            let p = (delta[i - 1] * h[i] + delta[i] * h[i - 1]) / (h[i - 1] + h[i]);
+            let lim = delta[i - 1].abs().min(delta[i].abs()).min(0.5 * p.abs());
+            d[i] = (sign(delta[i - 1]) + sign(delta[i])) * lim;
+        }
+
+        // Ends: parabola through the end three points, limited to twice the end slope
+        d[0] = end_slope(h[0], h[1], delta[0], delta[1]);
+        d[n - 1] = end_slope(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+
+        d
+    }
+}
+
+/// Sign of `a`, zero for zero
+fn sign(a: f64) -> f64 {
+    if a > 0.0 {
+        1.0
+    } else if a < 0.0 {
+        -1.0
+    } else {
+        0.0
+    }
+}
+
+/// Steffen's one-sided derivative estimate at an end knot
+/// #  Arguments
+/// * `h0` - Width of the end interval
+/// * `h1` - Width of the next interval in
+/// * `delta0` - Slope of the end interval
+/// * `delta1` - Slope of the next interval in
+fn end_slope(h0: f64, h1: f64, delta0: f64, delta1: f64) -> f64 {
+    let r = h0 / (h0 + h1);
+    let p = delta0 * (1.0 + r) - delta1 * r;
+
+    if p * delta0 <= 0.0 {
+        0.0
+    } else if p.abs() > 2.0 * delta0.abs() {
+        2.0 * delta0
+    } else {
+        p
+    }
+}
+
+impl Interpolate1D for Steffen1D {
+    type Dtype = f64;
+    type Data = Array1<Self::Dtype>;
+    type Index = usize;
+
+    /// Interpolation
+    /// #  Arguments
+    /// * `x` - The x value for which `f(x)` is being approximated
+    /// # Returns
+    /// * `y : f64` - An approximation of `f(x)`
+    fn interpolate(&mut self, x: Self::Dtype) -> Self::Dtype {
+        // Get indecies
+        let i = self.search.locate(&self.x, x);
+
+        // Evaluate
+        hermite_cubic(
+            x,
+            self.x[i],
+            self.x[i + 1],
+            self.y[i],
+            self.y[i + 1],
+            self.d[i],
+            self.d[i + 1],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{knots, non_decreasing, steps};
+
+    #[test]
+    fn stays_monotone_on_monotone_data() {
+        let (x, y) = steps();
+        let mut steffen = Steffen1D::new(x, y);
+
+        let samples = Array1::linspace(0., 7., 701).mapv(|x| steffen.interpolate(x));
+        assert!(non_decreasing(&samples));
+    }
+
+    #[test]
+    fn reproduces_linear_data() {
+        let x = knots();
+        let y = x.mapv(|x| 1.5 - 2.0 * x);
+        let mut steffen = Steffen1D::new(x, y);
+
+        for &x_q in Array1::linspace(-1., 2., 61).iter() {
+            assert!((steffen.interpolate(x_q) - (1.5 - 2.0 * x_q)).abs() < 1e-12);
+        }
+    }
+}
@@ -1,14 +1,39 @@
-// pub mod gaussj;
 pub mod interp {
+    pub mod akima;
+    pub mod barycentric;
+    pub mod bicubic;
     pub mod biliniar;
+    pub mod hermite;
     pub mod interpolator;
     pub mod linear;
+    pub mod multilinear;
+    pub mod pchip;
     pub mod polynomial;
+    pub mod polynomial2d;
     pub mod polynomial_ceoficients;
+    pub mod rational_function;
+    pub mod smoothing_spline;
     pub mod spline;
+    pub mod spline2d;
+    pub mod steffen;
+}
+
+pub mod linalg {
+    pub mod bandec;
+    pub mod cholesky;
+    pub mod gaussj;
+    pub mod ludcmp;
+    pub mod qrdcmp;
+    pub mod svd;
+    pub mod toeplz;
+    pub mod tridag;
+    pub mod vander;
 }
 
 pub mod table {
     pub mod bisect_hunt;
     pub mod search;
 }
+
+#[cfg(test)]
+mod test_utils;
@@ -0,0 +1,241 @@
+use crate::linalg::gaussj::SingularMatrix;
+use ndarray::prelude::*;
+
+/// Multiplies a band diagonal matrix by a vector
+/// #  Arguments
+/// * `a` - An `n x (m1 + m2 + 1)` compact band matrix. `A\[i\]\[j\]` is stored in `a\[i\]\[m1 + j - i\]`
+/// * `m1` - Number of sub-diagonals
+/// * `m2` - Number of super-diagonals
+/// * `x` - A vector of length `n`
+/// # Returns
+/// * `b : Array1<f64>` - The product `A x`
+pub fn banmul(a: &Array2<f64>, m1: usize, m2: usize, x: &Array1<f64>) -> Array1<f64> {
+    let n = a.nrows();
+
+    assert_eq!(
+        a.ncols(),
+        m1 + m2 + 1,
+        "banmul: `a` must have m1 + m2 + 1 columns"
+    );
+    assert_eq!(x.len(), n, "banmul: `x` has the wrong length");
+
+    Array1::from_shape_fn(n, |i| {
+        // Column j of the compact row holds A[i][i + j - m1]
+        let j_lo = m1.saturating_sub(i);
+        let j_hi = (m1 + m2 + 1).min(n + m1 - i);
+        (j_lo..j_hi).fold(0., |acc, j| acc + a[[i, j]] * x[i + j - m1])
+    })
+}
+
+/// LU decomposition of a band diagonal matrix with partial pivoting
+pub struct Bandec {
+    m1: usize,        // Number of sub-diagonals
+    m2: usize,        // Number of super-diagonals
+    au: Array2<f64>,  // Upper triangular factor, compact storage
+    al: Array2<f64>,  // Lower triangular multipliers, compact storage
+    indx: Vec<usize>, // Row permutation applied by partial pivoting
+    d: f64,           // +1 / -1 for an even / odd number of row interchanges
+}
+
+impl Bandec {
+    /// Constructor
+    /// #  Arguments
+    /// * `a` - An `n x (m1 + m2 + 1)` compact band matrix. `A\[i\]\[j\]` is stored in `a\[i\]\[m1 + j - i\]`
+    /// * `m1` - Number of sub-diagonals
+    /// * `m2` - Number of super-diagonals
+    /// # Returns
+    /// * `Result<Self, SingularMatrix>` - Bandec Instaciate, or `Err` if the matrix is singular
+    pub fn new(a: &Array2<f64>, m1: usize, m2: usize) -> Result<Self, SingularMatrix> {
+        let n = a.nrows();
+        let mm = m1 + m2 + 1;
+
+        assert_eq!(a.ncols(), mm, "Bandec: `a` must have m1 + m2 + 1 columns");
+
+        let mut au = a.clone();
+        let mut al = Array2::<f64>::zeros([n, m1]);
+        let mut indx = vec![0; n];
+        let mut d = 1.0;
+
+        // Rearrange the storage of the top rows so every row starts at column 0
+        let mut l = m1;
+        for i in 0..m1.min(n) {
+            for j in m1 - i..mm {
+                au[[i, j - l]] = au[[i, j]];
+            }
+            l -= 1;
+            for j in mm - l - 1..mm {
+                au[[i, j]] = 0.0;
+            }
+        }
+
+        let mut l = m1.min(n);
+        for k in 0..n {
+            // Find the pivot among the rows within the band
+            let mut dum = au[[k, 0]];
+            let mut i = k;
+            if l < n {
+                l += 1;
+            }
+            for j in k + 1..l {
+                if au[[j, 0]].abs() > dum.abs() {
+                    dum = au[[j, 0]];
+                    i = j;
+                }
+            }
+            indx[k] = i;
+
+            if dum == 0.0 {
+                return Err(SingularMatrix);
+            }
+
+            // Interchange rows
+            if i != k {
+                d = -d;
+                for j in 0..mm {
+                    au.swap([k, j], [i, j]);
+                }
+            }
+
+            // Eliminate
+            for i in k + 1..l {
+                let dum = au[[i, 0]] / au[[k, 0]];
+                al[[k, i - k - 1]] = dum;
+                for j in 1..mm {
+                    au[[i, j - 1]] = au[[i, j]] - dum * au[[k, j]];
+                }
+                au[[i, mm - 1]] = 0.0;
+            }
+        }
+
+        Ok(Self {
+            m1,
+            m2,
+            au,
+            al,
+            indx,
+            d,
+        })
+    }
+
+    /// Solves `A x = b`
+    /// #  Arguments
+    /// * `b` - A right-hand side of length `n`
+    /// # Returns
+    /// * `x : Array1<f64>` - The solution
+    pub fn solve(&self, b: &Array1<f64>) -> Array1<f64> {
+        let n = self.au.nrows();
+        let mm = self.m1 + self.m2 + 1;
+
+        assert_eq!(b.len(), n, "Bandec::solve: `b` has the wrong length");
+
+        let mut x = b.clone();
+
+        // Forward substitution, unscrambling the permutation as we go
+        let mut l = self.m1.min(n);
+        for k in 0..n {
+            x.swap(k, self.indx[k]);
+            if l < n {
+                l += 1;
+            }
+            for j in k + 1..l {
+                x[j] -= self.al[[k, j - k - 1]] * x[k];
+            }
+        }
+
+        // Back substitution
+        let mut l = 1;
+        for i in (0..n).rev() {
+            let mut dum = x[i];
+            for k in 1..l {
+                dum -= self.au[[i, k]] * x[k + i];
+            }
+            x[i] = dum / self.au[[i, 0]];
+            if l < mm {
+                l += 1;
+            }
+        }
+
+        x
+    }
+
+    /// Determinant of the factored matrix
+    pub fn det(&self) -> f64 {
+        self.au.column(0).fold(self.d, |acc, v| acc * v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::ludcmp::LUdcmp;
+    use crate::test_utils::max_abs;
+
+    // Compact storage of a dense matrix with m1 sub- and m2 super-diagonals
+    fn compact(a: &Array2<f64>, m1: usize, m2: usize) -> Array2<f64> {
+        let n = a.nrows();
+        Array2::from_shape_fn([n, m1 + m2 + 1], |(i, k)| {
+            let j = (i + k).wrapping_sub(m1);
+            if j < n {
+                a[[i, j]]
+            } else {
+                0.0
+            }
+        })
+    }
+
+    // Banded matrix with 2 sub- and 1 super-diagonal. Not diagonally dominant, so pivoting matters
+    fn banded() -> Array2<f64> {
+        let n = 7;
+        Array2::from_shape_fn([n, n], |(i, j)| {
+            if j + 2 < i || j > i + 1 {
+                0.0
+            } else {
+                ((3 * i + 5 * j) % 7) as f64 - 2.5
+            }
+        })
+    }
+
+    #[test]
+    fn banmul_matches_dense_product() {
+        let a = banded();
+        let x = Array1::linspace(-1., 2., 7);
+
+        assert!(max_abs(&(banmul(&compact(&a, 2, 1), 2, 1, &x) - &a.dot(&x))) < 1e-12);
+    }
+
+    #[test]
+    fn solve_and_det_match_dense_lu() {
+        let a = banded();
+        let b = Array1::linspace(1., 4., 7);
+        let band = Bandec::new(&compact(&a, 2, 1), 2, 1).unwrap();
+        let lu = LUdcmp::new(&a).unwrap();
+
+        let x = band.solve(&b);
+        assert!(max_abs(&(a.dot(&x) - &b)) < 1e-12);
+        assert!(max_abs(&(&x - &lu.solve(&b))) < 1e-12);
+        assert!((band.det() - lu.det()).abs() < 1e-9 * lu.det().abs());
+    }
+
+    #[test]
+    fn singular_matrix_is_rejected() {
+        // Second row is twice the first
+        let a = arr2(&[[1., 2., 0.], [2., 4., 0.], [0., 1., 3.]]);
+        assert!(matches!(
+            Bandec::new(&compact(&a, 1, 1), 1, 1),
+            Err(SingularMatrix)
+        ));
+    }
+
+    #[test]
+    fn fewer_rows_than_sub_diagonals() {
+        // Two rows, with as many or more sub-diagonals than rows
+        let a = arr2(&[[3., 1.], [2., 4.]]);
+        let b = arr1(&[5., 6.]);
+
+        for (m1, m2) in [(2, 2), (3, 1)] {
+            let band = Bandec::new(&compact(&a, m1, m2), m1, m2).unwrap();
+            assert!(max_abs(&(a.dot(&band.solve(&b)) - &b)) < 1e-12);
+            assert!((band.det() - 10.0).abs() < 1e-12);
+        }
+    }
+}
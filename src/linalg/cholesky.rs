@@ -0,0 +1,183 @@
+use ndarray::prelude::*;
+use std::fmt;
+
+/// Error returned when a matrix is not symmetric positive-definite
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotPositiveDefinite;
+
+impl fmt::Display for NotPositiveDefinite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "matrix is not positive-definite")
+    }
+}
+
+impl std::error::Error for NotPositiveDefinite {}
+
+/// Cholesky decomposition `A = L L^T` of a symmetric positive-definite matrix
+pub struct Cholesky {
+    el: Array2<f64>, // Lower triangular factor L. Upper triangle is zero
+}
+
+impl Cholesky {
+    /// Constructor
+    /// Only the upper triangle of `a` is read
+    /// #  Arguments
+    /// * `a` - An `n x n` symmetric positive-definite matrix
+    /// # Returns
+    /// * `Result<Self, NotPositiveDefinite>` - Cholesky Instaciate, or `Err` if `a` is not positive-definite
+    pub fn new(a: &Array2<f64>) -> Result<Self, NotPositiveDefinite> {
+        let n = a.nrows();
+
+        assert_eq!(a.ncols(), n, "Cholesky: `a` must be square");
+
+        let mut el = Array2::<f64>::zeros([n, n]);
+
+        for i in 0..n {
+            for j in i..n {
+                let sum = a[[i, j]] - el.slice(s![i, ..i]).dot(&el.slice(s![j, ..i]));
+                if i == j {
+                    // Fails on roundoff as well as on a genuinely indefinite matrix
+                    if sum <= 0.0 {
+                        return Err(NotPositiveDefinite);
+                    }
+                    el[[i, i]] = sum.sqrt();
+                } else {
+                    el[[j, i]] = sum / el[[i, i]];
+                }
+            }
+        }
+
+        Ok(Self { el })
+    }
+
+    /// Solves `A x = b`
+    /// #  Arguments
+    /// * `b` - A right-hand side of length `n`
+    /// # Returns
+    /// * `x : Array1<f64>` - The solution
+    pub fn solve(&self, b: &Array1<f64>) -> Array1<f64> {
+        let n = self.el.nrows();
+
+        // L y = b
+        let mut x = self.elsolve(b);
+
+        // L^T x = y
+        for i in (0..n).rev() {
+            let sum = x[i] - self.el.slice(s![i + 1.., i]).dot(&x.slice(s![i + 1..]));
+            x[i] = sum / self.el[[i, i]];
+        }
+
+        x
+    }
+
+    /// Multiplies by the lower triangular factor
+    /// Maps uncorrelated unit normal deviates `y` onto deviates with covariance `A`
+    /// #  Arguments
+    /// * `y` - A vector of length `n`
+    /// # Returns
+    /// * `b : Array1<f64>` - The product `L y`
+    pub fn elmult(&self, y: &Array1<f64>) -> Array1<f64> {
+        let n = self.el.nrows();
+
+        assert_eq!(y.len(), n, "Cholesky::elmult: `y` has the wrong length");
+
+        Array1::from_shape_fn(n, |i| self.el.slice(s![i, ..=i]).dot(&y.slice(s![..=i])))
+    }
+
+    /// Solves by the lower triangular factor
+    /// #  Arguments
+    /// * `b` - A right-hand side of length `n`
+    /// # Returns
+    /// * `y : Array1<f64>` - The solution to `L y = b`
+    pub fn elsolve(&self, b: &Array1<f64>) -> Array1<f64> {
+        let n = self.el.nrows();
+
+        assert_eq!(b.len(), n, "Cholesky::elsolve: `b` has the wrong length");
+
+        let mut y = Array1::<f64>::zeros([n]);
+        for i in 0..n {
+            let sum = b[i] - self.el.slice(s![i, ..i]).dot(&y.slice(s![..i]));
+            y[i] = sum / self.el[[i, i]];
+        }
+
+        y
+    }
+
+    /// Inverse of the factored matrix
+    pub fn inverse(&self) -> Array2<f64> {
+        let n = self.el.nrows();
+
+        let mut ainv = Array2::<f64>::zeros([n, n]);
+        for (j, mut col) in ainv.columns_mut().into_iter().enumerate() {
+            let mut e_j = Array1::<f64>::zeros([n]);
+            e_j[j] = 1.0;
+            col.assign(&self.solve(&e_j));
+        }
+
+        ainv
+    }
+
+    /// Natural log of the determinant. Positive-definite, so no sign is lost
+    pub fn log_det(&self) -> f64 {
+        2.0 * self.el.diag().fold(0., |acc, v| acc + v.ln())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::max_abs;
+
+    // B^T B + I is symmetric positive-definite
+    fn spd() -> Array2<f64> {
+        let b = arr2(&[
+            [2., -1., 0., 3.],
+            [1., 4., -2., 0.],
+            [0., 1., 3., -1.],
+            [-2., 0., 1., 1.],
+        ]);
+        b.t().dot(&b) + Array2::<f64>::eye(4)
+    }
+
+    #[test]
+    fn solves_and_inverts() {
+        let a = spd();
+        let b = arr1(&[1., -2., 3., 0.5]);
+        let chol = Cholesky::new(&a).unwrap();
+
+        let x = chol.solve(&b);
+        assert!(max_abs(&(a.dot(&x) - &b)) < 1e-12);
+        assert!(max_abs(&(a.dot(&chol.inverse()) - Array2::<f64>::eye(4))) < 1e-12);
+    }
+
+    #[test]
+    fn factor_reproduces_matrix_and_determinant() {
+        let a = spd();
+        let chol = Cholesky::new(&a).unwrap();
+
+        assert!(max_abs(&(chol.el.dot(&chol.el.t()) - &a)) < 1e-12);
+
+        let det = crate::linalg::ludcmp::LUdcmp::new(&a).unwrap().det();
+        assert!((chol.log_det() - det.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn elsolve_inverts_elmult() {
+        let chol = Cholesky::new(&spd()).unwrap();
+        let y = arr1(&[0.3, -1.2, 2.5, 0.7]);
+
+        let y_back = chol.elsolve(&chol.elmult(&y));
+        assert!(max_abs(&(y_back - &y)) < 1e-12);
+    }
+
+    #[test]
+    fn indefinite_matrix_is_rejected() {
+        // Eigenvalues 3 and -1
+        let a = arr2(&[[1., 2.], [2., 1.]]);
+        assert_eq!(Cholesky::new(&a).err(), Some(NotPositiveDefinite));
+
+        // Semi-definite
+        let a = arr2(&[[1., 1.], [1., 1.]]);
+        assert_eq!(Cholesky::new(&a).err(), Some(NotPositiveDefinite));
+    }
+}
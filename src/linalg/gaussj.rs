@@ -0,0 +1,192 @@
+use ndarray::prelude::*;
+use ndarray::{DataMut, Zip};
+use std::fmt;
+
+/// Error returned when a linear system has no unique solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SingularMatrix;
+
+impl fmt::Display for SingularMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "singular matrix")
+    }
+}
+
+impl std::error::Error for SingularMatrix {}
+
+/// Swaps rows `i` and `j` of `a` in place
+/// Pass `a.view_mut().reversed_axes()` to swap columns instead
+pub(crate) fn swap_row<S>(a: &mut ArrayBase<S, Ix2>, i: usize, j: usize)
+where
+    S: DataMut<Elem = f64>,
+{
+    if i == j {
+        return;
+    }
+    let (mut row_i, mut row_j) = a.multi_slice_mut((s![i, ..], s![j, ..]));
+    Zip::from(&mut row_i)
+        .and(&mut row_j)
+        .for_each(std::mem::swap);
+}
+
+/// Gauss-Jordan elimination with full pivoting
+/// #  Arguments
+/// * `a` - An `n x n` coefficient matrix. Replaced by its inverse
+/// * `b` - An `n x m` matrix of right-hand sides. Replaced by the corresponding solutions
+/// # Returns
+/// * `Result<(), SingularMatrix>` - `Err` if `a` is singular, in which case `a` and `b` are left partially reduced
+pub fn gaussj(a: &mut Array2<f64>, b: &mut Array2<f64>) -> Result<(), SingularMatrix> {
+    let n = a.nrows();
+
+    assert_eq!(a.ncols(), n, "gaussj: `a` must be square");
+    assert_eq!(b.nrows(), n, "gaussj: `b` must have as many rows as `a`");
+
+    // Bookkeeping on the pivoting
+    let mut indxc = vec![0; n];
+    let mut indxr = vec![0; n];
+    let mut ipiv = vec![false; n];
+
+    for i in 0..n {
+        // Search for the largest remaining element by absolute value
+        let mut big = 0.;
+        let (mut irow, mut icol) = (0, 0);
+
+        for j in 0..n {
+            if ipiv[j] {
+                continue;
+            }
+            for k in 0..n {
+                if !ipiv[k] && a[[j, k]].abs() >= big {
+                    big = a[[j, k]].abs();
+                    irow = j;
+                    icol = k;
+                }
+            }
+        }
+
+        ipiv[icol] = true;
+
+        // Move the pivot onto the diagonal. Columns are relabelled, not moved
+        if irow != icol {
+            swap_row(a, irow, icol);
+            swap_row(b, irow, icol);
+        }
+
+        indxr[i] = irow;
+        indxc[i] = icol;
+
+        if a[[icol, icol]] == 0.0 {
+            return Err(SingularMatrix);
+        }
+
+        // Normalise the pivot row
+        let pivinv = 1.0 / a[[icol, icol]];
+        a[[icol, icol]] = 1.0;
+        a.row_mut(icol).mapv_inplace(|v| v * pivinv);
+        b.row_mut(icol).mapv_inplace(|v| v * pivinv);
+
+        // Reduce every other row
+        let a_piv = a.row(icol).to_owned();
+        let b_piv = b.row(icol).to_owned();
+
+        for ll in 0..n {
+            if ll != icol {
+                let dum = a[[ll, icol]];
+                a[[ll, icol]] = 0.0;
+                a.row_mut(ll).scaled_add(-dum, &a_piv);
+                b.row_mut(ll).scaled_add(-dum, &b_piv);
+            }
+        }
+    }
+
+    // Unscramble the inverse by swapping columns in reverse order
+    for l in (0..n).rev() {
+        if indxr[l] != indxc[l] {
+            swap_row(&mut a.view_mut().reversed_axes(), indxr[l], indxc[l]);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn proof() {
+    println!("Starting proof...");
+
+    // Test data
+    let a = arr2(&[
+        [4., -2., 1., 3.],
+        [3., 6., -4., 2.],
+        [2., 1., 8., -5.],
+        [1., -3., 2., 7.],
+    ]);
+    let b = arr2(&[[1., 0.], [2., 3.], [4., 5.], [6., 7.]]);
+
+    let mut a_inv = a.clone();
+    let mut x = b.clone();
+
+    gaussj(&mut a_inv, &mut x).unwrap();
+
+    // Run tests
+    let res = &b - &a.dot(&x);
+    println!("\nSolution residual:\t{:.3e}", res.mapv(f64::abs).sum());
+
+    let res = Array2::<f64>::eye(a.nrows()) - a.dot(&a_inv);
+    println!("Inverse residual:\t{:.3e}", res.mapv(f64::abs).sum());
+
+    println!("Proof complete.");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{matrix, max_abs};
+
+    #[test]
+    fn solves_and_inverts() {
+        let a = matrix();
+        let b = arr2(&[[1., 0.], [2., 3.], [4., 5.], [6., 7.]]);
+
+        let mut a_inv = a.clone();
+        let mut x = b.clone();
+        gaussj(&mut a_inv, &mut x).unwrap();
+
+        assert!(max_abs(&(a.dot(&x) - &b)) < 1e-12);
+        assert!(max_abs(&(a.dot(&a_inv) - Array2::<f64>::eye(4))) < 1e-12);
+        assert!(max_abs(&(a_inv.dot(&a) - Array2::<f64>::eye(4))) < 1e-12);
+    }
+
+    #[test]
+    fn off_diagonal_pivots_are_unscrambled() {
+        // Zero diagonal, so every pivot is found off the diagonal and columns must be swapped back
+        let a = arr2(&[[0., 2., 1.], [1., 0., 5.], [7., 1., 0.]]);
+        let b = arr2(&[[1.], [2.], [3.]]);
+
+        let mut a_inv = a.clone();
+        let mut x = b.clone();
+        gaussj(&mut a_inv, &mut x).unwrap();
+
+        assert!(max_abs(&(a.dot(&x) - &b)) < 1e-12);
+        assert!(max_abs(&(a.dot(&a_inv) - Array2::<f64>::eye(3))) < 1e-12);
+        assert!(max_abs(&(a_inv.dot(&a) - Array2::<f64>::eye(3))) < 1e-12);
+    }
+
+    #[test]
+    fn singular_matrix_is_rejected() {
+        // Second row is twice the first
+        let mut a = arr2(&[[2., 1., 3.], [4., 2., 6.], [1., 5., 2.]]);
+        let mut b = Array2::<f64>::ones([3, 1]);
+
+        assert_eq!(gaussj(&mut a, &mut b), Err(SingularMatrix));
+    }
+
+    #[test]
+    fn swap_row_swaps_rows_and_columns() {
+        let mut a = arr2(&[[1., 2.], [3., 4.]]);
+
+        swap_row(&mut a, 0, 1);
+        assert_eq!(a, arr2(&[[3., 4.], [1., 2.]]));
+
+        swap_row(&mut a.view_mut().reversed_axes(), 0, 1);
+        assert_eq!(a, arr2(&[[4., 3.], [2., 1.]]));
+    }
+}
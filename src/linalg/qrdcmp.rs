@@ -0,0 +1,282 @@
+use crate::linalg::gaussj::SingularMatrix;
+use ndarray::prelude::*;
+
+/// QR decomposition of an `m x n` matrix, `m >= n`, by Householder reflections
+/// `Q` is stored explicitly (as its transpose) so the decomposition can be updated
+/// For `m > n`, `solve` returns the least-squares solution
+pub struct QRdcmp {
+    qt: Array2<f64>, // Transpose of the orthogonal factor Q, m x m
+    r: Array2<f64>,  // Upper triangular factor R, m x n
+    sing: bool,      // R has a zero on its diagonal?
+}
+
+impl QRdcmp {
+    /// Constructor
+    /// #  Arguments
+    /// * `a` - An `m x n` matrix with `m >= n`
+    /// # Returns
+    /// * `Self` - QRdcmp Instaciate. Check `is_singular` before solving
+    pub fn new(a: &Array2<f64>) -> Self {
+        let (m, n) = a.dim();
+
+        assert!(
+            m >= n,
+            "QRdcmp: `a` must have at least as many rows as columns"
+        );
+
+        let mut r = a.clone();
+        let mut c = Array1::<f64>::zeros([n]);
+        let mut d = Array1::<f64>::zeros([n]);
+        let mut sing = false;
+
+        for k in 0..n {
+            // The last column of a square matrix needs no reflection
+            if k + 1 == m {
+                d[k] = r[[k, k]];
+                sing |= d[k] == 0.0;
+                continue;
+            }
+
+            let scale = r.slice(s![k.., k]).fold(0., |acc: f64, v| acc.max(v.abs()));
+
+            if scale == 0.0 {
+                // Singular case
+                sing = true;
+                continue;
+            }
+
+            // Form Q_k and Q_k A
+            r.slice_mut(s![k.., k]).mapv_inplace(|v| v / scale);
+
+            let sum = r.slice(s![k.., k]).fold(0., |acc, v| acc + v * v);
+            let sigma = sum.sqrt().copysign(r[[k, k]]);
+
+            r[[k, k]] += sigma;
+            c[k] = sigma * r[[k, k]];
+            d[k] = -scale * sigma;
+
+            let v_k = r.slice(s![k.., k]).to_owned();
+            for j in k + 1..n {
+                let tau = v_k.dot(&r.slice(s![k.., j])) / c[k];
+                r.slice_mut(s![k.., j]).scaled_add(-tau, &v_k);
+            }
+        }
+
+        // Accumulate Q^T from the stored reflections
+        let mut qt = Array2::<f64>::eye(m);
+        for k in 0..n {
+            if c[k] == 0.0 {
+                continue;
+            }
+            let v_k = r.slice(s![k.., k]).to_owned();
+            for j in 0..m {
+                let sum = v_k.dot(&qt.slice(s![k.., j])) / c[k];
+                qt.slice_mut(s![k.., j]).scaled_add(-sum, &v_k);
+            }
+        }
+
+        // Overwrite the reflection vectors to leave R
+        for k in 0..n {
+            r[[k, k]] = d[k];
+            r.slice_mut(s![k + 1.., k]).fill(0.0);
+        }
+
+        Self { qt, r, sing }
+    }
+
+    /// Whether `R` has a zero on its diagonal, i.e. `a` is rank deficient
+    pub fn is_singular(&self) -> bool {
+        self.sing
+    }
+
+    /// Solves `A x = b`, in the least-squares sense if `m > n`
+    /// #  Arguments
+    /// * `b` - A right-hand side of length `m`
+    /// # Returns
+    /// * `Result<Array1<f64>, SingularMatrix>` - The solution of length `n`
+    pub fn solve(&self, b: &Array1<f64>) -> Result<Array1<f64>, SingularMatrix> {
+        let n = self.r.ncols();
+        let qtb = self.qtmult(b);
+        self.rsolve(&qtb.slice(s![..n]).to_owned())
+    }
+
+    /// Multiplies by `Q^T`
+    /// #  Arguments
+    /// * `b` - A vector of length `m`
+    /// # Returns
+    /// * `x : Array1<f64>` - The product `Q^T b`
+    pub fn qtmult(&self, b: &Array1<f64>) -> Array1<f64> {
+        assert_eq!(
+            b.len(),
+            self.qt.nrows(),
+            "QRdcmp::qtmult: `b` has the wrong length"
+        );
+        self.qt.dot(b)
+    }
+
+    /// Solves the triangular system `R x = b`
+    /// #  Arguments
+    /// * `b` - A right-hand side of length `n`
+    /// # Returns
+    /// * `Result<Array1<f64>, SingularMatrix>` - The solution of length `n`
+    pub fn rsolve(&self, b: &Array1<f64>) -> Result<Array1<f64>, SingularMatrix> {
+        let n = self.r.ncols();
+
+        assert_eq!(b.len(), n, "QRdcmp::rsolve: `b` has the wrong length");
+
+        if self.sing {
+            return Err(SingularMatrix);
+        }
+
+        let mut x = b.clone();
+        for i in (0..n).rev() {
+            let sum = x[i] - self.r.slice(s![i, i + 1..]).dot(&x.slice(s![i + 1..n]));
+            x[i] = sum / self.r[[i, i]];
+        }
+
+        Ok(x)
+    }
+
+    /// Updates the decomposition of `A` to that of `A + u v^T` in `O(m^2)` operations
+    /// #  Arguments
+    /// * `u` - A vector of length `m`
+    /// * `v` - A vector of length `n`
+    pub fn update(&mut self, u: &Array1<f64>, v: &Array1<f64>) {
+        let (m, n) = self.r.dim();
+
+        assert_eq!(u.len(), m, "QRdcmp::update: `u` has the wrong length");
+        assert_eq!(v.len(), n, "QRdcmp::update: `v` has the wrong length");
+
+        // A + u v^T = Q (R + w v^T)
+        let mut w = self.qtmult(u);
+
+        // Find the last nonzero element of w
+        let k = (0..m).rev().find(|&k| w[k] != 0.0).unwrap_or(0);
+
+        // Rotate w onto e_0, leaving R upper Hessenberg
+        for i in (0..k).rev() {
+            self.rotate(i, w[i], -w[i + 1]);
+            w[i] = w[i].hypot(w[i + 1]);
+        }
+
+        self.r.row_mut(0).scaled_add(w[0], v);
+
+        // Rotate R back to upper triangular
+        for i in 0..k.min(n) {
+            self.rotate(i, self.r[[i, i]], -self.r[[i + 1, i]]);
+        }
+
+        self.sing = self.r.diag().iter().any(|&r_ii| r_ii == 0.0);
+    }
+
+    /// Jacobi rotation of rows `i` and `i + 1` of `R` and `Q^T`
+    /// #  Arguments
+    /// * `i` - The upper row to rotate
+    /// * `a` - Rotation is chosen so `cos = a / sqrt(a^2 + b^2)`
+    /// * `b` - Rotation is chosen so `sin = b / sqrt(a^2 + b^2)`
+    fn rotate(&mut self, i: usize, a: f64, b: f64) {
+        let (c, s) = if a == 0.0 {
+            (0.0, if b >= 0.0 { 1.0 } else { -1.0 })
+        } else if a.abs() > b.abs() {
+            let fact = b / a;
+            let c = (1.0 / (1.0 + fact * fact).sqrt()).copysign(a);
+            (c, fact * c)
+        } else {
+            let fact = a / b;
+            let s = (1.0 / (1.0 + fact * fact).sqrt()).copysign(b);
+            (fact * s, s)
+        };
+
+        let n = self.r.ncols();
+        for j in i.min(n)..n {
+            let (y, w) = (self.r[[i, j]], self.r[[i + 1, j]]);
+            self.r[[i, j]] = c * y - s * w;
+            self.r[[i + 1, j]] = s * y + c * w;
+        }
+
+        for j in 0..self.qt.ncols() {
+            let (y, w) = (self.qt[[i, j]], self.qt[[i + 1, j]]);
+            self.qt[[i, j]] = c * y - s * w;
+            self.qt[[i + 1, j]] = s * y + c * w;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::ludcmp::LUdcmp;
+    use crate::test_utils::{matrix, max_abs};
+
+    fn tall() -> Array2<f64> {
+        Array2::from_shape_fn([7, 3], |(i, j)| {
+            ((2 * i + 3 * j) % 5) as f64 - 1.5 + (i * j) as f64 * 0.1
+        })
+    }
+
+    // Least-squares solution from the normal equations
+    fn normal_solve(a: &Array2<f64>, b: &Array1<f64>) -> Array1<f64> {
+        LUdcmp::new(&a.t().dot(a)).unwrap().solve(&a.t().dot(b))
+    }
+
+    #[test]
+    fn factors_reproduce_matrix() {
+        for a in [matrix(), tall()] {
+            let qr = QRdcmp::new(&a);
+            let q_r = qr.qt.t().dot(&qr.r);
+
+            assert!(max_abs(&(q_r - &a)) < 1e-12);
+            assert!(max_abs(&(qr.qt.dot(&qr.qt.t()) - Array2::<f64>::eye(a.nrows()))) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn solves_square_and_least_squares_systems() {
+        let a = matrix();
+        let b = arr1(&[1., 2., 4., 6.]);
+        let x = QRdcmp::new(&a).solve(&b).unwrap();
+        assert!(max_abs(&(a.dot(&x) - &b)) < 1e-12);
+
+        let a = tall();
+        let b = Array1::linspace(-1., 2., 7);
+        let x = QRdcmp::new(&a).solve(&b).unwrap();
+        assert!(max_abs(&(&x - &normal_solve(&a, &b))) < 1e-10);
+    }
+
+    #[test]
+    fn update_matches_fresh_factorisation() {
+        for a in [matrix(), tall()] {
+            let (m, n) = a.dim();
+            let u = Array1::linspace(0.5, -1.0, m);
+            let v = Array1::linspace(1.0, 2.0, n);
+            let b = Array1::linspace(-1., 2., m);
+
+            let mut qr = QRdcmp::new(&a);
+            qr.update(&u, &v);
+
+            let a_new = &a
+                + &u.view()
+                    .insert_axis(Axis(1))
+                    .dot(&v.view().insert_axis(Axis(0)));
+            let fresh = QRdcmp::new(&a_new);
+
+            let x = qr.solve(&b).unwrap();
+            assert!(max_abs(&(&x - &fresh.solve(&b).unwrap())) < 1e-10);
+            assert!(max_abs(&(&x - &normal_solve(&a_new, &b))) < 1e-10);
+        }
+    }
+
+    #[test]
+    fn singular_matrix_is_reported() {
+        // Zero column: R has an exact zero on its diagonal
+        let a = arr2(&[[1., 0., 2.], [3., 0., 1.], [5., 0., 4.]]);
+        let qr = QRdcmp::new(&a);
+
+        assert!(qr.is_singular());
+        assert_eq!(qr.solve(&arr1(&[1., 1., 1.])), Err(SingularMatrix));
+
+        // Second column twice the first: zero up to roundoff
+        let a = arr2(&[[1., 2., 0.], [2., 4., 1.], [3., 6., 5.]]);
+        assert!(QRdcmp::new(&a).r[[1, 1]].abs() < 1e-12);
+    }
+}
@@ -0,0 +1,463 @@
+use ndarray::prelude::*;
+use ndarray::Zip;
+use std::fmt;
+
+/// Error returned when the SVD's QR iteration fails to converge
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SvdNoConvergence;
+
+impl fmt::Display for SvdNoConvergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no convergence in 30 svd iterations")
+    }
+}
+
+impl std::error::Error for SvdNoConvergence {}
+
+/// Singular value decomposition `A = U W V^T` of an `m x n` matrix
+/// Singular values are sorted into descending order
+pub struct SVD {
+    u: Array2<f64>, // Left singular vectors, m x n
+    v: Array2<f64>, // Right singular vectors, n x n
+    w: Array1<f64>, // Singular values, descending
+    tsh: f64,       // Default threshold below which singular values are treated as zero
+}
+
+impl SVD {
+    /// Constructor
+    /// #  Arguments
+    /// * `a` - An `m x n` matrix
+    /// # Returns
+    /// * `Result<Self, SvdNoConvergence>` - SVD Instaciate
+    pub fn new(a: &Array2<f64>) -> Result<Self, SvdNoConvergence> {
+        let (m, n) = a.dim();
+
+        let mut u = a.clone();
+        let mut v = Array2::<f64>::zeros([n, n]);
+        let mut w = Array1::<f64>::zeros([n]);
+
+        Self::decompose(&mut u, &mut v, &mut w)?;
+
+        // Sort into descending order of singular value
+        let mut idx: Vec<usize> = (0..n).collect();
+        idx.sort_by(|&i, &j| w[j].total_cmp(&w[i]));
+
+        let mut u = u.select(Axis(1), &idx);
+        let mut v = v.select(Axis(1), &idx);
+        let w = w.select(Axis(0), &idx);
+
+        // Flip signs so most elements of each singular vector pair are positive
+        for k in 0..n {
+            let negative = u.column(k).iter().filter(|&&e| e < 0.0).count()
+                + v.column(k).iter().filter(|&&e| e < 0.0).count();
+            if negative > (m + n) / 2 {
+                u.column_mut(k).mapv_inplace(|e| -e);
+                v.column_mut(k).mapv_inplace(|e| -e);
+            }
+        }
+
+        let w_max = if n > 0 { w[0] } else { 0.0 };
+        let tsh = 0.5 * ((m + n + 1) as f64).sqrt() * w_max * f64::EPSILON;
+
+        Ok(Self { u, v, w, tsh })
+    }
+
+    /// Left singular vectors, one per column
+    pub fn u(&self) -> &Array2<f64> {
+        &self.u
+    }
+
+    /// Singular values, in descending order
+    pub fn w(&self) -> &Array1<f64> {
+        &self.w
+    }
+
+    /// Right singular vectors, one per column
+    pub fn v(&self) -> &Array2<f64> {
+        &self.v
+    }
+
+    /// Solves `A x = b` through the pseudo-inverse
+    /// Singular values at or below the threshold are zeroed, giving the minimum-norm
+    /// least-squares solution
+    /// #  Arguments
+    /// * `b` - A right-hand side of length `m`
+    /// * `thresh` - Singular value threshold. `None` for a default based on machine precision
+    /// # Returns
+    /// * `x : Array1<f64>` - The solution of length `n`
+    pub fn solve(&self, b: &Array1<f64>, thresh: Option<f64>) -> Array1<f64> {
+        assert_eq!(
+            b.len(),
+            self.u.nrows(),
+            "SVD::solve: `b` has the wrong length"
+        );
+
+        let thresh = self.thresh(thresh);
+
+        let mut tmp = self.u.t().dot(b);
+        Zip::from(&mut tmp).and(&self.w).for_each(|t, &w_j| {
+            *t = if w_j > thresh { *t / w_j } else { 0.0 };
+        });
+
+        self.v.dot(&tmp)
+    }
+
+    /// Number of singular values above the threshold
+    /// #  Arguments
+    /// * `thresh` - Singular value threshold. `None` for a default based on machine precision
+    pub fn rank(&self, thresh: Option<f64>) -> usize {
+        let thresh = self.thresh(thresh);
+        self.w.iter().filter(|&&w_j| w_j > thresh).count()
+    }
+
+    /// Number of singular values at or below the threshold
+    /// #  Arguments
+    /// * `thresh` - Singular value threshold. `None` for a default based on machine precision
+    pub fn nullity(&self, thresh: Option<f64>) -> usize {
+        self.w.len() - self.rank(thresh)
+    }
+
+    /// Orthonormal basis for the range of `A`, one vector per column
+    /// #  Arguments
+    /// * `thresh` - Singular value threshold. `None` for a default based on machine precision
+    pub fn range(&self, thresh: Option<f64>) -> Array2<f64> {
+        let rank = self.rank(thresh);
+        self.u.slice(s![.., ..rank]).to_owned()
+    }
+
+    /// Orthonormal basis for the nullspace of `A`, one vector per column
+    /// #  Arguments
+    /// * `thresh` - Singular value threshold. `None` for a default based on machine precision
+    pub fn nullspace(&self, thresh: Option<f64>) -> Array2<f64> {
+        let rank = self.rank(thresh);
+        self.v.slice(s![.., rank..]).to_owned()
+    }
+
+    /// Reciprocal of the condition number. Zero if `A` is singular
+    pub fn inv_condition(&self) -> f64 {
+        let n = self.w.len();
+        if n == 0 || self.w[0] <= 0.0 || self.w[n - 1] <= 0.0 {
+            0.0
+        } else {
+            self.w[n - 1] / self.w[0]
+        }
+    }
+
+    fn thresh(&self, thresh: Option<f64>) -> f64 {
+        thresh.unwrap_or(self.tsh)
+    }
+
+    /// Golub-Reinsch decomposition: Householder reduction to bidiagonal form followed
+    /// by implicitly shifted QR iteration
+    /// #  Arguments
+    /// * `u` - On input the matrix `a`, on output the left singular vectors
+    /// * `v` - On output the right singular vectors
+    /// * `w` - On output the (unsorted) singular values
+    fn decompose(
+        u: &mut Array2<f64>,
+        v: &mut Array2<f64>,
+        w: &mut Array1<f64>,
+    ) -> Result<(), SvdNoConvergence> {
+        let (m, n) = u.dim();
+        let eps = f64::EPSILON;
+
+        let mut rv1 = Array1::<f64>::zeros([n]);
+        let (mut g, mut scale, mut anorm) = (0.0_f64, 0.0_f64, 0.0_f64);
+        let mut l = 0;
+
+        // Householder reduction to bidiagonal form
+        for i in 0..n {
+            l = i + 1;
+            rv1[i] = scale * g;
+            g = 0.0;
+            scale = 0.0;
+
+            if i < m {
+                scale = u.slice(s![i.., i]).fold(0., |acc, e| acc + e.abs());
+                if scale != 0.0 {
+                    u.slice_mut(s![i.., i]).mapv_inplace(|e| e / scale);
+                    let s = u.slice(s![i.., i]).fold(0., |acc, e| acc + e * e);
+                    let f = u[[i, i]];
+                    g = -sign(s.sqrt(), f);
+                    let h = f * g - s;
+                    u[[i, i]] = f - g;
+                    for j in l..n {
+                        let s = u.slice(s![i.., i]).dot(&u.slice(s![i.., j]));
+                        let f = s / h;
+                        for k in i..m {
+                            u[[k, j]] += f * u[[k, i]];
+                        }
+                    }
+                    u.slice_mut(s![i.., i]).mapv_inplace(|e| e * scale);
+                }
+            }
+
+            w[i] = scale * g;
+            g = 0.0;
+            scale = 0.0;
+
+            if i < m && i + 1 != n {
+                scale = u.slice(s![i, l..]).fold(0., |acc, e| acc + e.abs());
+                if scale != 0.0 {
+                    u.slice_mut(s![i, l..]).mapv_inplace(|e| e / scale);
+                    let s = u.slice(s![i, l..]).fold(0., |acc, e| acc + e * e);
+                    let f = u[[i, l]];
+                    g = -sign(s.sqrt(), f);
+                    let h = f * g - s;
+                    u[[i, l]] = f - g;
+                    for k in l..n {
+                        rv1[k] = u[[i, k]] / h;
+                    }
+                    for j in l..m {
+                        let s = u.slice(s![j, l..]).dot(&u.slice(s![i, l..]));
+                        for k in l..n {
+                            u[[j, k]] += s * rv1[k];
+                        }
+                    }
+                    u.slice_mut(s![i, l..]).mapv_inplace(|e| e * scale);
+                }
+            }
+
+            anorm = anorm.max(w[i].abs() + rv1[i].abs());
+        }
+
+        // Accumulation of right-hand transformations
+        for i in (0..n).rev() {
+            if i < n - 1 {
+                if g != 0.0 {
+                    // Double division avoids possible underflow
+                    for j in l..n {
+                        v[[j, i]] = (u[[i, j]] / u[[i, l]]) / g;
+                    }
+                    for j in l..n {
+                        let s = (l..n).fold(0., |acc, k| acc + u[[i, k]] * v[[k, j]]);
+                        for k in l..n {
+                            v[[k, j]] += s * v[[k, i]];
+                        }
+                    }
+                }
+                for j in l..n {
+                    v[[i, j]] = 0.0;
+                    v[[j, i]] = 0.0;
+                }
+            }
+            v[[i, i]] = 1.0;
+            g = rv1[i];
+            l = i;
+        }
+
+        // Accumulation of left-hand transformations
+        for i in (0..m.min(n)).rev() {
+            let l = i + 1;
+            let g = w[i];
+            for j in l..n {
+                u[[i, j]] = 0.0;
+            }
+            if g != 0.0 {
+                let g = 1.0 / g;
+                for j in l..n {
+                    let s = (l..m).fold(0., |acc, k| acc + u[[k, i]] * u[[k, j]]);
+                    let f = (s / u[[i, i]]) * g;
+                    for k in i..m {
+                        u[[k, j]] += f * u[[k, i]];
+                    }
+                }
+                u.slice_mut(s![i.., i]).mapv_inplace(|e| e * g);
+            } else {
+                u.slice_mut(s![i.., i]).fill(0.0);
+            }
+            u[[i, i]] += 1.0;
+        }
+
+        // Diagonalisation of the bidiagonal form
+        for k in (0..n).rev() {
+            for its in 0..30 {
+                // Test for splitting
+                let mut flag = true;
+                let mut l = k;
+                loop {
+                    if l == 0 || rv1[l].abs() <= eps * anorm {
+                        flag = false;
+                        break;
+                    }
+                    if w[l - 1].abs() <= eps * anorm {
+                        break;
+                    }
+                    l -= 1;
+                }
+
+                // Cancellation of rv1[l], if l > 0
+                if flag {
+                    let nm = l - 1;
+                    let mut c = 0.0;
+                    let mut s = 1.0;
+                    for i in l..k + 1 {
+                        let f = s * rv1[i];
+                        rv1[i] *= c;
+                        if f.abs() <= eps * anorm {
+                            break;
+                        }
+                        let g = w[i];
+                        let h = f.hypot(g);
+                        w[i] = h;
+                        c = g / h;
+                        s = -f / h;
+                        for j in 0..m {
+                            let y = u[[j, nm]];
+                            let z = u[[j, i]];
+                            u[[j, nm]] = y * c + z * s;
+                            u[[j, i]] = z * c - y * s;
+                        }
+                    }
+                }
+
+                // Convergence. Make the singular value non-negative
+                let z = w[k];
+                if l == k {
+                    if z < 0.0 {
+                        w[k] = -z;
+                        v.column_mut(k).mapv_inplace(|e| -e);
+                    }
+                    break;
+                }
+
+                if its == 29 {
+                    return Err(SvdNoConvergence);
+                }
+
+                // Shift from bottom 2-by-2 minor
+                let mut x = w[l];
+                let nm = k - 1;
+                let mut y = w[nm];
+                let mut g = rv1[nm];
+                let mut h = rv1[k];
+                let mut f = ((y - z) * (y + z) + (g - h) * (g + h)) / (2.0 * h * y);
+                g = f.hypot(1.0);
+                f = ((x - z) * (x + z) + h * ((y / (f + sign(g, f))) - h)) / x;
+
+                // Next QR transformation
+                let mut c = 1.0;
+                let mut s = 1.0;
+                for j in l..=nm {
+                    let i = j + 1;
+                    g = rv1[i];
+                    y = w[i];
+                    h = s * g;
+                    g *= c;
+                    let mut z = f.hypot(h);
+                    rv1[j] = z;
+                    c = f / z;
+                    s = h / z;
+                    f = x * c + g * s;
+                    g = g * c - x * s;
+                    h = y * s;
+                    y *= c;
+                    for jj in 0..n {
+                        let x = v[[jj, j]];
+                        let z = v[[jj, i]];
+                        v[[jj, j]] = x * c + z * s;
+                        v[[jj, i]] = z * c - x * s;
+                    }
+                    z = f.hypot(h);
+                    w[j] = z;
+                    // Rotation can be arbitrary if z = 0
+                    if z != 0.0 {
+                        c = f / z;
+                        s = h / z;
+                    }
+                    f = c * g + s * y;
+                    x = c * y - s * g;
+                    for jj in 0..m {
+                        let y = u[[jj, j]];
+                        let z = u[[jj, i]];
+                        u[[jj, j]] = y * c + z * s;
+                        u[[jj, i]] = z * c - y * s;
+                    }
+                }
+                rv1[l] = 0.0;
+                rv1[k] = f;
+                w[k] = x;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Magnitude of `a` with the sign of `b`
+fn sign(a: f64, b: f64) -> f64 {
+    if b >= 0.0 {
+        a.abs()
+    } else {
+        -a.abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::max_abs;
+
+    fn tall() -> Array2<f64> {
+        Array2::from_shape_fn([6, 4], |(i, j)| {
+            ((3 * i + 2 * j) % 7) as f64 - 2.0 + 0.3 * (i * j) as f64
+        })
+    }
+
+    // Rank 2: third column is the sum of the first two, fourth is twice the first
+    fn rank_deficient() -> Array2<f64> {
+        let a = arr2(&[[1., 2.], [-1., 0.5], [3., 1.], [0., -2.], [2., 2.]]);
+        let mut b = Array2::<f64>::zeros([5, 4]);
+        b.slice_mut(s![.., ..2]).assign(&a);
+        b.column_mut(2).assign(&(&a.column(0) + &a.column(1)));
+        b.column_mut(3).assign(&(2.0 * &a.column(0)));
+        b
+    }
+
+    #[test]
+    fn reconstructs_matrix() {
+        for a in [tall(), rank_deficient()] {
+            let svd = SVD::new(&a).unwrap();
+            let n = a.ncols();
+
+            let usv = svd.u().dot(&Array2::from_diag(svd.w())).dot(&svd.v().t());
+            assert!(max_abs(&(usv - &a)) < 1e-12);
+
+            // Orthonormal columns, non-negative descending singular values
+            assert!(max_abs(&(svd.u().t().dot(svd.u()) - Array2::<f64>::eye(n))) < 1e-12);
+            assert!(max_abs(&(svd.v().t().dot(svd.v()) - Array2::<f64>::eye(n))) < 1e-12);
+            assert!(svd.w().windows(2).into_iter().all(|w| w[0] >= w[1]));
+            assert!(svd.w().iter().all(|&w| w >= 0.0));
+        }
+    }
+
+    #[test]
+    fn rank_nullity_and_nullspace() {
+        let a = rank_deficient();
+        let svd = SVD::new(&a).unwrap();
+
+        assert_eq!(svd.rank(None), 2);
+        assert_eq!(svd.nullity(None), 2);
+        assert_eq!(svd.range(None).ncols(), 2);
+
+        let null = svd.nullspace(None);
+        assert_eq!(null.ncols(), 2);
+        assert!(max_abs(&a.dot(&null)) < 1e-12);
+        assert!(svd.inv_condition() < 1e-14);
+
+        let svd = SVD::new(&tall()).unwrap();
+        assert_eq!(svd.rank(None), 4);
+        assert_eq!(svd.nullity(None), 0);
+    }
+
+    #[test]
+    fn solve_gives_least_squares_solution() {
+        let a = tall();
+        let b = Array1::linspace(-1., 2., 6);
+        let x = SVD::new(&a).unwrap().solve(&b, None);
+
+        // Residual is orthogonal to the range of A
+        let r = a.dot(&x) - &b;
+        assert!(max_abs(&a.t().dot(&r)) < 1e-12);
+    }
+}
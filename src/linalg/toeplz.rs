@@ -0,0 +1,116 @@
+use crate::linalg::gaussj::SingularMatrix;
+use ndarray::prelude::*;
+
+/// Solves the Toeplitz system `sum_j R\[n-1+i-j\] x\[j\] = y\[i\]` by Levinson's method in `O(n^2)` operations
+/// #  Arguments
+/// * `r` - The `2n-1` distinct matrix elements. `r\[n-1\]` is the diagonal
+/// * `y` - Right-hand side `y\[0\], ..., y\[n-1\]`
+/// # Returns
+/// * `Result<Array1<f64>, SingularMatrix>` - The solution, or `Err` if a leading principal minor is singular
+pub fn toeplz(r: &Array1<f64>, y: &Array1<f64>) -> Result<Array1<f64>, SingularMatrix> {
+    let n = y.len();
+
+    if n == 0 {
+        assert!(r.is_empty(), "toeplz: `r` must be empty when `y` is");
+        return Ok(Array1::zeros([0]));
+    }
+
+    assert_eq!(r.len(), 2 * n - 1, "toeplz: `r` must have length 2n - 1");
+
+    let n1 = n - 1;
+    let mut x = Array1::<f64>::zeros([n]);
+
+    if r[n1] == 0.0 {
+        return Err(SingularMatrix);
+    }
+    x[0] = y[0] / r[n1];
+
+    if n1 == 0 {
+        return Ok(x);
+    }
+
+    // Solutions of the auxiliary left and right recursions
+    let mut g = Array1::<f64>::zeros([n1]);
+    let mut h = Array1::<f64>::zeros([n1]);
+    g[0] = r[n1 - 1] / r[n1];
+    h[0] = r[n1 + 1] / r[n1];
+
+    for m in 0..n1 {
+        let m1 = m + 1;
+
+        // Extend the solution x by one element
+        let mut sxn = -y[m1];
+        let mut sd = -r[n1];
+        for j in 0..m + 1 {
+            sxn += r[n1 + m1 - j] * x[j];
+            sd += r[n1 + m1 - j] * g[m - j];
+        }
+        if sd == 0.0 {
+            return Err(SingularMatrix);
+        }
+        x[m1] = sxn / sd;
+        for j in 0..m + 1 {
+            x[j] -= x[m1] * g[m - j];
+        }
+
+        if m1 == n1 {
+            break;
+        }
+
+        // Extend g and h by one element
+        let mut sgn = -r[n1 - m1 - 1];
+        let mut shn = -r[n1 + m1 + 1];
+        let mut sgd = -r[n1];
+        for j in 0..m + 1 {
+            sgn += r[n1 + j - m1] * g[j];
+            shn += r[n1 + m1 - j] * h[j];
+            sgd += r[n1 + j - m1] * h[m - j];
+        }
+        if sgd == 0.0 {
+            return Err(SingularMatrix);
+        }
+        g[m1] = sgn / sgd;
+        h[m1] = shn / sd;
+
+        // Update the remaining elements of g and h, working in from both ends
+        let pp = g[m1];
+        let qq = h[m1];
+        let mut k = m;
+        for j in 0..(m + 2) >> 1 {
+            let (pt1, pt2) = (g[j], g[k]);
+            let (qt1, qt2) = (h[j], h[k]);
+            g[j] = pt1 - pp * qt2;
+            g[k] = pt2 - pp * qt1;
+            h[j] = qt1 - qq * pt2;
+            h[k] = qt2 - qq * pt1;
+            k = k.saturating_sub(1);
+        }
+    }
+
+    Ok(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::max_abs;
+
+    #[test]
+    fn matches_dense_system() {
+        let r = arr1(&[0.5, -1., 2., 6., 1.5, -0.5, 0.25]);
+        let y = arr1(&[1., -2., 3., 0.5]);
+        let n = y.len();
+
+        let x = toeplz(&r, &y).unwrap();
+        let a = Array2::from_shape_fn([n, n], |(i, j)| r[n - 1 + i - j]);
+
+        assert!(max_abs(&(a.dot(&x) - &y)) < 1e-12);
+    }
+
+    #[test]
+    fn handles_trivial_sizes() {
+        assert_eq!(toeplz(&arr1(&[]), &arr1(&[])).unwrap().len(), 0);
+        assert_eq!(toeplz(&arr1(&[4.]), &arr1(&[2.])).unwrap(), arr1(&[0.5]));
+        assert_eq!(toeplz(&arr1(&[0.]), &arr1(&[2.])), Err(SingularMatrix));
+    }
+}
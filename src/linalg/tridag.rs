@@ -0,0 +1,153 @@
+use ndarray::prelude::*;
+
+/// Solves a tridiagonal system by forward elimination and back substitution
+/// No pivoting is performed, so the system should be diagonally dominant
+/// #  Arguments
+/// * `a` - Sub-diagonal `a\[1\], ..., a\[n-1\]`. `a\[0\]` is ignored
+/// * `b` - Diagonal `b\[0\], ..., b\[n-1\]`
+/// * `c` - Super-diagonal `c\[0\], ..., c\[n-2\]`. `c\[n-1\]` is ignored
+/// * `r` - Right-hand side `r\[0\], ..., r\[n-1\]`
+/// # Returns
+/// * `u : Array1<f64>` - The solution
+pub fn tridag(a: &Array1<f64>, b: &Array1<f64>, c: &Array1<f64>, r: &Array1<f64>) -> Array1<f64> {
+    let n = b.len();
+
+    assert!(
+        a.len() == n && c.len() == n && r.len() == n,
+        "tridag: `a`, `b`, `c` and `r` must have equal length"
+    );
+
+    let mut u = Array1::<f64>::zeros([n]);
+    let mut gam = Array1::<f64>::zeros([n]);
+
+    // Decomposition and forward substitution
+    let mut bet = b[0];
+    if bet == 0.0 {
+        panic!("Bad input to routine tridag.")
+    }
+    u[0] = r[0] / bet;
+
+    for j in 1..n {
+        gam[j] = c[j - 1] / bet;
+        bet = b[j] - a[j] * gam[j];
+        if bet == 0.0 {
+            panic!("Zero pivot in routine tridag.")
+        }
+        u[j] = (r[j] - a[j] * u[j - 1]) / bet;
+    }
+
+    // Back substitution
+    for j in (0..n - 1).rev() {
+        u[j] -= gam[j + 1] * u[j + 1];
+    }
+
+    u
+}
+
+/// Solves a cyclic tridiagonal system via the Sherman-Morrison formula
+/// #  Arguments
+/// * `a` - Sub-diagonal, as for `tridag`
+/// * `b` - Diagonal, as for `tridag`
+/// * `c` - Super-diagonal, as for `tridag`
+/// * `alpha` - Bottom-left corner element `A\[n-1\]\[0\]`
+/// * `beta` - Top-right corner element `A\[0\]\[n-1\]`
+/// * `r` - Right-hand side `r\[0\], ..., r\[n-1\]`
+/// # Returns
+/// * `x : Array1<f64>` - The solution
+pub fn cyclic(
+    a: &Array1<f64>,
+    b: &Array1<f64>,
+    c: &Array1<f64>,
+    alpha: f64,
+    beta: f64,
+    r: &Array1<f64>,
+) -> Array1<f64> {
+    let n = b.len();
+
+    if n <= 2 {
+        panic!("n too small in routine cyclic.")
+    }
+
+    // Avoids subtraction error in forming bb[0]
+    let gamma = -b[0];
+
+    // Modified tridiagonal system
+    let mut bb = b.clone();
+    bb[0] = b[0] - gamma;
+    bb[n - 1] = b[n - 1] - alpha * beta / gamma;
+
+    let mut x = tridag(a, &bb, c, r);
+
+    // Vector u of the rank-one correction
+    let mut u = Array1::<f64>::zeros([n]);
+    u[0] = gamma;
+    u[n - 1] = alpha;
+
+    let z = tridag(a, &bb, c, &u);
+
+    let fact = (x[0] + beta * x[n - 1] / gamma) / (1.0 + z[0] + beta * z[n - 1] / gamma);
+    x.scaled_add(-fact, &z);
+
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::ludcmp::LUdcmp;
+    use crate::test_utils::max_abs;
+
+    fn diagonals() -> [Array1<f64>; 4] {
+        [
+            arr1(&[0., 1., -2., 0.5, 1.5, -1.]),
+            arr1(&[5., 6., 7., 5.5, 8., 6.]),
+            arr1(&[2., -1., 1., 3., -0.5, 0.]),
+            arr1(&[1., 2., 3., -1., 0.5, 4.]),
+        ]
+    }
+
+    // Dense matrix of the tridiagonal system, with optional corner elements
+    fn dense(
+        a: &Array1<f64>,
+        b: &Array1<f64>,
+        c: &Array1<f64>,
+        alpha: f64,
+        beta: f64,
+    ) -> Array2<f64> {
+        let n = b.len();
+        let mut m = Array2::<f64>::zeros([n, n]);
+        for i in 0..n {
+            m[[i, i]] = b[i];
+            if i > 0 {
+                m[[i, i - 1]] = a[i];
+            }
+            if i + 1 < n {
+                m[[i, i + 1]] = c[i];
+            }
+        }
+        m[[n - 1, 0]] += alpha;
+        m[[0, n - 1]] += beta;
+        m
+    }
+
+    #[test]
+    fn tridag_matches_dense_solve() {
+        let [a, b, c, r] = diagonals();
+        let x = tridag(&a, &b, &c, &r);
+
+        let m = dense(&a, &b, &c, 0.0, 0.0);
+        assert!(max_abs(&(m.dot(&x) - &r)) < 1e-12);
+        assert!(max_abs(&(&x - &LUdcmp::new(&m).unwrap().solve(&r))) < 1e-12);
+    }
+
+    #[test]
+    fn cyclic_matches_dense_solve_with_corners() {
+        let [a, b, c, r] = diagonals();
+        let (alpha, beta) = (1.25, -0.75);
+        let x = cyclic(&a, &b, &c, alpha, beta, &r);
+
+        let m = dense(&a, &b, &c, alpha, beta);
+        assert!(max_abs(&(m.dot(&x) - &r)) < 1e-12);
+        assert!(max_abs(&(&x - &LUdcmp::new(&m).unwrap().solve(&r))) < 1e-12);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::linalg::gaussj::SingularMatrix;
+use ndarray::prelude::*;
+
+/// Solves the Vandermonde system `sum_i x\[i\]^k w\[i\] = q\[k\]`, `k = 0, ..., n-1`, in `O(n^2)` operations
+/// This is the transpose of the system solved when fitting polynomial coefficients
+/// #  Arguments
+/// * `x` - Distinct nodes `x\[0\], ..., x\[n-1\]`
+/// * `q` - Right-hand side `q\[0\], ..., q\[n-1\]`
+/// # Returns
+/// * `Result<Array1<f64>, SingularMatrix>` - The weights `w`, or `Err` if two nodes coincide
+pub fn vander(x: &Array1<f64>, q: &Array1<f64>) -> Result<Array1<f64>, SingularMatrix> {
+    let n = q.len();
+
+    assert_eq!(x.len(), n, "vander: `x` and `q` must have equal length");
+
+    if n <= 1 {
+        return Ok(q.clone());
+    }
+
+    // Coefficients of the master polynomial prod_i (z - x[i])
+    let mut c = Array1::<f64>::zeros([n]);
+    c[n - 1] = -x[0];
+    for i in 1..n {
+        let xx = -x[i];
+        for j in n - 1 - i..n - 1 {
+            c[j] += xx * c[j + 1];
+        }
+        c[n - 1] += xx;
+    }
+
+    let mut w = Array1::<f64>::zeros([n]);
+    for i in 0..n {
+        // Synthetic division by (z - x[i]), evaluating the quotient as we go
+        let xx = x[i];
+        let mut t = 1.0;
+        let mut b = 1.0;
+        let mut s = q[n - 1];
+        for k in (1..n).rev() {
+            b = c[k] + xx * b;
+            s += q[k - 1] * b;
+            t = xx * t + b;
+        }
+        if t == 0.0 {
+            return Err(SingularMatrix);
+        }
+        w[i] = s / t;
+    }
+
+    Ok(w)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::max_abs;
+
+    #[test]
+    fn matches_dense_system() {
+        let x = arr1(&[-1.5, -0.2, 0.4, 1.1, 2.3]);
+        let q = arr1(&[1., -2., 3., 0.5, -1.]);
+        let n = x.len();
+
+        let w = vander(&x, &q).unwrap();
+        let a = Array2::from_shape_fn([n, n], |(k, i)| x[i].powi(k as i32));
+
+        assert!(max_abs(&(a.dot(&w) - &q)) < 1e-10);
+    }
+
+    #[test]
+    fn handles_trivial_sizes_and_repeated_nodes() {
+        assert_eq!(vander(&arr1(&[]), &arr1(&[])).unwrap().len(), 0);
+        assert_eq!(vander(&arr1(&[3.]), &arr1(&[2.])).unwrap(), arr1(&[2.]));
+        assert_eq!(
+            vander(&arr1(&[1., 2., 1.]), &arr1(&[1., 1., 1.])),
+            Err(SingularMatrix)
+        );
+    }
+}
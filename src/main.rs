@@ -1,11 +1,13 @@
-// use recipies::interp::biliniar;
+use recipies::interp::biliniar;
 use recipies::interp::linear;
 use recipies::interp::polynomial_ceoficients;
 use recipies::interp::spline;
+use recipies::linalg::gaussj;
 
 fn main() {
+    gaussj::proof();
     linear::proof();
     polynomial_ceoficients::proof();
     spline::proof();
-    // biliniar::proof();
+    biliniar::proof();
 }
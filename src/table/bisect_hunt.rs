@@ -5,7 +5,7 @@ use std::cmp;
 
 /// Bookmarking variables
 pub struct BisectHunt1D {
-    m: usize,              // Interval size : x centered in x[j_lo], ..., x[j_lo + m]
+    m: usize,              // Bracket size : x centered in x[j_lo], ..., x[j_lo + m - 1]
     corr: bool,            // Previous searches coreelated?
     ascend: bool,          // x ascending?
     dj: usize,             // Determines when worth hunting
@@ -19,10 +19,10 @@ impl BisectHunt1D {
         let ascend = table[table.len() - 1] > table[0];
 
         Self {
-            m: m,
+            m,
             corr: false,
-            ascend: ascend,
-            dj: dj,
+            ascend,
+            dj,
             i_save: None,
         }
     }
@@ -83,7 +83,7 @@ impl BisectHunt1D {
                         break;
                     }
                     // Update bracket
-                    lower = lower - inc;
+                    lower -= inc;
 
                     // Value bracketed -> Sucess!
                     if (x >= table[lower]) == self.ascend {
@@ -100,7 +100,7 @@ impl BisectHunt1D {
 
         // Bisect on identified bracket
         while upper - lower > 1 {
-            let mid = upper + lower >> 1; // Calculate midpoint
+            let mid = (upper + lower) >> 1; // Calculate midpoint
             match (x >= table[mid]) == self.ascend {
                 true => lower = mid,
                 false => upper = mid,
@@ -108,20 +108,17 @@ impl BisectHunt1D {
         }
 
         // If previous value was saved, determine if current point is close to previosu value
-        if self.i_save.is_some() {
-            self.corr = lower.abs_diff(self.i_save.unwrap()) < self.dj
+        if let Some(i_save) = self.i_save {
+            self.corr = lower.abs_diff(i_save) < self.dj
         }
 
         // Save location
         self.i_save = Some(lower);
 
-        if lower < self.m {
-            return 0;
-        } else if lower > table.len() - self.m - 1 {
-            return table.len() - self.m - 1;
-        } else {
-            return lower - ((self.m - 2) >> 1);
-        }
+        // Centre the m point bracket on x, keeping it inside the table
+        lower
+            .saturating_sub(self.m.saturating_sub(2) >> 1)
+            .min(table.len() - self.m)
     }
 }
 
@@ -131,6 +128,6 @@ impl Search for BisectHunt1D {
     type Index = usize;
 
     fn locate(&mut self, table: &Self::Input, x: f64) -> Self::Index {
-        return self.hunt_bisect(table, x);
+        self.hunt_bisect(table, x)
     }
 }
@@ -0,0 +1,43 @@
+use ndarray::prelude::*;
+
+/// Well conditioned 4 x 4 matrix, neither symmetric nor banded
+pub(crate) fn matrix() -> Array2<f64> {
+    arr2(&[
+        [4., -2., 1., 3.],
+        [3., 6., -4., 2.],
+        [2., 1., 8., -5.],
+        [1., -3., 2., 7.],
+    ])
+}
+
+/// Largest absolute entry of `a`
+pub(crate) fn max_abs<D: Dimension>(a: &Array<f64, D>) -> f64 {
+    a.fold(0., |acc, v| v.abs().max(acc))
+}
+
+/// Uneven, ascending knots on `\[-1, 2\]`, with one close pair
+pub(crate) fn knots() -> Array1<f64> {
+    arr1(&[-1.0, -0.7, -0.1, 0.2, 0.25, 0.9, 1.4, 2.0])
+}
+
+/// Monotone data with flat steps and a sharp rise
+pub(crate) fn steps() -> (Array1<f64>, Array1<f64>) {
+    (
+        arr1(&[0.0, 1.0, 2.0, 2.5, 3.0, 4.5, 6.0, 7.0]),
+        arr1(&[0.0, 0.0, 0.0, 1.0, 5.0, 5.0, 5.2, 10.0]),
+    )
+}
+
+/// True if `y` never decreases by more than rounding
+/// Cubic Hermite basis weights do not sum to exactly one, so a flat segment can dip by an ulp
+pub(crate) fn non_decreasing(y: &Array1<f64>) -> bool {
+    y.windows(2).into_iter().all(|w| w[1] >= w[0] - 1e-12)
+}
+
+/// Uneven, ascending 5 x 4 grid axes on `\[-1, 2\] x \[0, 1.5\]`, with one close pair on the second axis
+pub(crate) fn grid() -> (Array1<f64>, Array1<f64>) {
+    (
+        arr1(&[-1.0, -0.4, 0.3, 1.1, 2.0]),
+        arr1(&[0.0, 0.5, 0.6, 1.5]),
+    )
+}